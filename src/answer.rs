@@ -0,0 +1,33 @@
+#![allow(clippy::needless_return)]
+use std::fmt;
+use num_format::{Locale, ToFormattedString};
+
+//Every puzzle answer this year is an integer. Unsigned results are stored as i64 since none come close to overflowing it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Answer(pub i64);
+
+impl Answer {
+    pub fn get_value(&self) -> i64 {
+        return self.0;
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "{}", self.0.to_formatted_string(&Locale::en));
+    }
+}
+
+macro_rules! impl_answer_from {
+    ($($source:ty),*) => {
+        $(
+            impl From<$source> for Answer {
+                fn from(value: $source) -> Self {
+                    return Answer(i64::try_from(value).expect("Answer does not fit in an i64"));
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from!(i32, u32, i64, u64, usize);
@@ -0,0 +1,208 @@
+#![allow(clippy::needless_return)]
+use std::env;
+use std::path::PathBuf;
+use std::process::exit;
+use sandbox::bench::*;
+use sandbox::registry::*;
+use sandbox::output::OutputFormat;
+use sandbox::runner::*;
+use sandbox::verify::*;
+
+const USAGE: &str = "\
+Usage:
+    aoc list                                List every registered solver
+    aoc run <day> [part] [--input <path>]   Run one part, or every part of a day if no part is given
+    aoc run --all                           Run every solver in order
+    aoc bench <day> [part] | --all          Time the parse and solve phases of the selected solvers
+    aoc verify [<day> [part]]               Check answers against the accepted ones, all solvers by default
+
+Options for run:
+    --format text|json|plain                Print the solver's sentence (default), one JSON object
+                                            per answer, or just the number
+
+Options for bench:
+    --iterations <n>                        Timed runs per solver after a warm up run (default 10)
+    --output <path>                         Write the results as JSON if the path ends in .json,
+                                            otherwise as CSV
+    --baseline <path>                       Compare median times against a previous --output file
+
+Options for verify:
+    --answers <path>                        Accepted answers, with a [day<N>] table holding
+                                            part<N> = <answer> keys. Defaults to answers.toml in
+                                            $AOC_INPUT_DIR if set, otherwise in the current directory
+
+Input is read from --input if given ('-' reads stdin), otherwise from
+$AOC_INPUT_DIR/day<N>.txt if AOC_INPUT_DIR is set, otherwise from the
+solver's input file under src/.";
+
+struct BenchOptions {
+    iterations: usize,
+    output: Option<PathBuf>,
+    baseline: Option<PathBuf>
+}
+
+fn main() {
+    let mut args = env::args().skip(1).collect::<Vec<String>>();
+    let input_argument = take_option(&mut args, "--input", USAGE);
+    let format = take_output_format(&mut args).unwrap_or_else(|x| exit_with_usage(&x, USAGE));
+    let iterations = take_option(&mut args, "--iterations", USAGE);
+    let bench_options = BenchOptions {
+        iterations: iterations.as_ref().map_or(DEFAULT_ITERATIONS, |x| parse_number(x, "iteration count") as usize),
+        output: take_option(&mut args, "--output", USAGE).map(PathBuf::from),
+        baseline: take_option(&mut args, "--baseline", USAGE).map(PathBuf::from)
+    };
+    let answers_path = take_option(&mut args, "--answers", USAGE).map(PathBuf::from);
+    let args = args.iter().map(|x| x.as_str()).collect::<Vec<&str>>();
+
+    if args.first() != Some(&"bench") && (iterations.is_some() || bench_options.output.is_some() || bench_options.baseline.is_some()) {
+        exit_with_usage("--iterations, --output, and --baseline can only be used with bench", USAGE);
+    }
+    if args.first() != Some(&"verify") && answers_path.is_some() {
+        exit_with_usage("--answers can only be used with verify", USAGE);
+    }
+    //The answers file belongs to a whole input set, so verify always reads the usual inputs
+    if args.first() == Some(&"verify") && input_argument.is_some() {
+        exit_with_usage("--input can't be used with verify, set AOC_INPUT_DIR instead", USAGE);
+    }
+
+    match args.as_slice() {
+        ["list"] => list_solvers(),
+        ["run", selection @ ..] => run_solvers(select_solvers(selection), input_argument, format),
+        ["bench", selection @ ..] => bench_solvers(select_solvers(selection), input_argument, &bench_options),
+        ["verify"] => verify_solvers(SOLVERS.iter().collect(), answers_path),
+        ["verify", selection @ ..] => verify_solvers(select_solvers(selection), answers_path),
+        ["help"] | ["--help"] | ["-h"] => println!("{}", USAGE),
+        _ => exit_with_usage("Invalid arguments", USAGE)
+    }
+}
+
+fn list_solvers() {
+    for solver in SOLVERS.iter() {
+        println!("{}", solver.get_name());
+    }
+}
+
+//Parses "<day> [part]" or "--all"
+fn select_solvers(selection: &[&str]) -> Vec<&'static Solver> {
+    let solvers = match selection {
+        ["--all"] => SOLVERS.iter().collect(),
+        [day] => find_day_solvers(parse_number(day, "day")),
+        [day, part] => {
+            let day = parse_number(day, "day");
+            let part = parse_number(part, "part");
+            match find_solver(day, part) {
+                Some(solver) => vec![solver],
+                None => exit_with_usage(&format!("No solver registered for day {} part {}", day, part), USAGE)
+            }
+        },
+        _ => exit_with_usage("Invalid arguments", USAGE)
+    };
+
+    if solvers.is_empty() {
+        exit_with_usage("No solvers registered for that day", USAGE);
+    }
+
+    return solvers;
+}
+
+fn run_solvers(solvers: Vec<&Solver>, input_argument: Option<String>, format: OutputFormat) {
+    //Headers would break up the JSON lines and plain number output
+    let print_header = solvers.len() > 1 && format == OutputFormat::Text;
+    for_each_input(solvers, input_argument, |solver, input| {
+        if print_header {
+            println!("--- {} ---", solver.get_name());
+        }
+
+        run_solver(solver, input, format);
+    });
+}
+
+fn bench_solvers(solvers: Vec<&Solver>, input_argument: Option<String>, options: &BenchOptions) {
+    let baseline = options.baseline.as_ref().map(|path| match read_results(path) {
+        Ok(x) => x,
+        Err(error) => {
+            eprintln!("Could not read baseline {}: {}", path.display(), error);
+            exit(1);
+        }
+    });
+
+    println!("Times are min / median / p95 in ms over {} runs", options.iterations);
+    let mut results = Vec::<BenchResult>::new();
+    for_each_input(solvers, input_argument, |solver, input| {
+        match bench_solver(solver, input, options.iterations) {
+            Ok(result) => {
+                let baseline_result = baseline
+                    .iter()
+                    .flatten()
+                    .find(|x| x.day == result.day && x.part == result.part);
+                println!("{}", format_bench_result(&result, baseline_result));
+                results.push(result);
+            },
+            Err(error) => {
+                eprintln!("{} failed: {}", solver.get_name(), error);
+                exit(1);
+            }
+        }
+    });
+
+    if let Some(path) = &options.output {
+        if let Err(error) = write_results(path, &results) {
+            eprintln!("Could not write results to {}: {}", path.display(), error);
+            exit(1);
+        }
+    }
+}
+
+fn verify_solvers(solvers: Vec<&Solver>, answers_path: Option<PathBuf>) {
+    let answers_path = answers_path.unwrap_or_else(AnswerKey::default_path);
+    let answer_key = match AnswerKey::load(&answers_path) {
+        Ok(x) => x,
+        Err(error) => {
+            eprintln!("Could not read answers from {}: {}", answers_path.display(), error);
+            exit(1);
+        }
+    };
+
+    let mut verdicts = Vec::<Verdict>::new();
+    for solver in solvers {
+        let verdict = verify_solver(solver, &answer_key);
+        println!("{:<15} {}", solver.get_name(), verdict);
+        verdicts.push(verdict);
+    }
+
+    let count = |predicate: fn(&Verdict) -> bool| verdicts.iter().filter(|x| predicate(x)).count();
+    println!("\n{} passed, {} failed, {} missing, {} errors",
+        count(|x| *x == Verdict::Pass),
+        count(|x| matches!(x, Verdict::Fail { .. })),
+        count(|x| matches!(x, Verdict::Missing { .. })),
+        count(|x| matches!(x, Verdict::Error(_))));
+
+    if verdicts.iter().any(|x| x.is_failure()) {
+        exit(1);
+    }
+}
+
+//Calls action with each solver and its input
+fn for_each_input(solvers: Vec<&Solver>, input_argument: Option<String>, mut action: impl FnMut(&Solver, &str)) {
+    //Different days can't share an input, so an explicit input is only allowed for a single day
+    if input_argument.is_some() && solvers.iter().any(|x| x.day != solvers[0].day) {
+        exit_with_usage("--input can only be used when running a single day", USAGE);
+    }
+
+    //Read an explicit input once so that stdin can be shared between the parts of a day
+    let shared_input = input_argument.map(|x| read_input(solvers[0], Some(&x)));
+
+    for solver in solvers {
+        match &shared_input {
+            Some(input) => action(solver, input),
+            None => action(solver, &read_input(solver, None))
+        }
+    }
+}
+
+fn parse_number(text: &str, name: &str) -> u32 {
+    return match text.parse::<u32>() {
+        Ok(x) => x,
+        Err(_) => exit_with_usage(&format!("Invalid {} '{}'", name, text), USAGE)
+    };
+}
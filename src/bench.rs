@@ -0,0 +1,191 @@
+#![allow(clippy::needless_return)]
+use std::cell::Cell;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+use crate::answer::Answer;
+use crate::error::SolverError;
+use crate::registry::Solver;
+
+pub const DEFAULT_ITERATIONS: usize = 10;
+
+const CSV_HEADER: &str = "day,part,iterations,parse_min_ms,parse_median_ms,parse_p95_ms,\
+solve_min_ms,solve_median_ms,solve_p95_ms,total_min_ms,total_median_ms,total_p95_ms";
+
+thread_local! {
+    static PARSE_END: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+//Solvers call this once their input is parsed so that the parse and solve phases can be timed separately.
+//Solvers that parse as they go don't call it, and all of their time is counted as solve time.
+pub fn end_parse_phase() {
+    PARSE_END.with(|x| x.set(Some(Instant::now())));
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PhaseTimes {
+    pub parse: Duration,
+    pub solve: Duration
+}
+
+impl PhaseTimes {
+    pub fn get_total(&self) -> Duration {
+        return self.parse + self.solve;
+    }
+}
+
+//Runs the solver once, splitting the elapsed time at the solver's end_parse_phase() call
+pub fn time_solver(solver: &Solver, input: &str) -> Result<(Answer, PhaseTimes), SolverError> {
+    PARSE_END.with(|x| x.set(None));
+    let start = Instant::now();
+    let answer = solver.run(input)?;
+    let end = Instant::now();
+    let parse_end = PARSE_END.with(|x| x.take()).unwrap_or(start);
+
+    return Ok((answer, PhaseTimes { parse: parse_end - start, solve: end - parse_end }));
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Statistics {
+    pub min_ms: f64,
+    pub median_ms: f64,
+    pub p95_ms: f64
+}
+
+impl Statistics {
+    pub fn from_durations(durations: &[Duration]) -> Self {
+        let mut sorted = durations.iter().map(|x| x.as_secs_f64() * 1000f64).collect::<Vec<f64>>();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+
+        return Statistics {
+            min_ms: percentile(&sorted, 0f64),
+            median_ms: percentile(&sorted, 0.5f64),
+            p95_ms: percentile(&sorted, 0.95f64)
+        };
+    }
+}
+
+//Nearest-rank percentile of already sorted values
+fn percentile(sorted: &[f64], fraction: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0f64;
+    }
+
+    let rank = (fraction * sorted.len() as f64).ceil() as usize;
+    return sorted[rank.clamp(1, sorted.len()) - 1];
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchResult {
+    pub day: u32,
+    pub part: u32,
+    pub iterations: usize,
+    pub parse: Statistics,
+    pub solve: Statistics,
+    pub total: Statistics
+}
+
+impl BenchResult {
+    pub fn get_name(&self) -> String {
+        return format!("Day {} part {}", self.day, self.part);
+    }
+}
+
+//Runs the solver once to warm up and check the input, then the given number of timed iterations
+pub fn bench_solver(solver: &Solver, input: &str, iterations: usize) -> Result<BenchResult, SolverError> {
+    time_solver(solver, input)?;
+
+    let mut times = Vec::<PhaseTimes>::new();
+    for _ in 0..iterations {
+        times.push(time_solver(solver, input)?.1);
+    }
+
+    return Ok(BenchResult {
+        day: solver.day,
+        part: solver.part,
+        iterations,
+        parse: Statistics::from_durations(&times.iter().map(|x| x.parse).collect::<Vec<Duration>>()),
+        solve: Statistics::from_durations(&times.iter().map(|x| x.solve).collect::<Vec<Duration>>()),
+        total: Statistics::from_durations(&times.iter().map(|x| x.get_total()).collect::<Vec<Duration>>())
+    });
+}
+
+//One line summary, optionally compared against the same solver's result from an earlier run
+pub fn format_bench_result(result: &BenchResult, baseline: Option<&BenchResult>) -> String {
+    let format_statistics = |x: &Statistics| format!("{:.3} / {:.3} / {:.3}", x.min_ms, x.median_ms, x.p95_ms);
+    let mut line = format!("{:<15} parse {:<28} solve {:<28} total {}",
+        result.get_name(), format_statistics(&result.parse), format_statistics(&result.solve), format_statistics(&result.total));
+
+    if let Some(baseline) = baseline {
+        //A baseline too fast to measure has no meaningful percentage change
+        let change = if baseline.total.median_ms > 0f64 {
+            format!("{:+.1}%", (result.total.median_ms - baseline.total.median_ms) / baseline.total.median_ms * 100f64)
+        } else {
+            "n/a".to_string()
+        };
+        line.push_str(&format!("  ({} median vs baseline)", change));
+    }
+
+    return line;
+}
+
+//Results are written as JSON if the path ends in .json, otherwise as CSV
+pub fn write_results(path: &Path, results: &[BenchResult]) -> io::Result<()> {
+    if is_json_path(path) {
+        return fs::write(path, serde_json::to_string_pretty(results)?);
+    }
+
+    let mut lines = vec![CSV_HEADER.to_string()];
+    for result in results {
+        let mut fields = vec![result.day.to_string(), result.part.to_string(), result.iterations.to_string()];
+        for statistics in [&result.parse, &result.solve, &result.total] {
+            fields.extend([statistics.min_ms, statistics.median_ms, statistics.p95_ms].map(|x| x.to_string()));
+        }
+
+        lines.push(fields.join(","));
+    }
+
+    return fs::write(path, lines.join("\n") + "\n");
+}
+
+//Reads a file written by write_results
+pub fn read_results(path: &Path) -> io::Result<Vec<BenchResult>> {
+    let contents = fs::read_to_string(path)?;
+    if is_json_path(path) {
+        return Ok(serde_json::from_str(&contents)?);
+    }
+
+    let invalid_data = |message: String| io::Error::new(io::ErrorKind::InvalidData, message);
+    return contents
+        .lines()
+        .skip(1)
+        .filter(|x| !x.is_empty())
+        .map(|line| {
+            let fields = line.split(",").collect::<Vec<&str>>();
+            if fields.len() != CSV_HEADER.split(",").count() {
+                return Err(invalid_data(format!("Expected {} fields: '{}'", CSV_HEADER.split(",").count(), line)));
+            }
+
+            let numbers = fields[3..]
+                .iter()
+                .map(|x| x.parse::<f64>().map_err(|_| invalid_data(format!("Invalid number '{}'", x))))
+                .collect::<Result<Vec<f64>, io::Error>>()?;
+            let statistics = |index: usize| Statistics { min_ms: numbers[index], median_ms: numbers[index + 1], p95_ms: numbers[index + 2] };
+
+            return Ok(BenchResult {
+                day: fields[0].parse().map_err(|_| invalid_data(format!("Invalid day '{}'", fields[0])))?,
+                part: fields[1].parse().map_err(|_| invalid_data(format!("Invalid part '{}'", fields[1])))?,
+                iterations: fields[2].parse().map_err(|_| invalid_data(format!("Invalid iteration count '{}'", fields[2])))?,
+                parse: statistics(0),
+                solve: statistics(3),
+                total: statistics(6)
+            });
+        })
+        .collect();
+}
+
+fn is_json_path(path: &Path) -> bool {
+    return path.extension().is_some_and(|x| x == "json");
+}
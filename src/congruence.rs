@@ -0,0 +1,73 @@
+#![allow(clippy::needless_return)]
+use num::integer::Integer;
+
+//The integers x with x = residue (mod modulus). The residue is always kept in 0..modulus.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Congruence {
+    residue: u64,
+    modulus: u64
+}
+
+impl Congruence {
+    //Panics if the modulus is zero
+    pub fn new(residue: u64, modulus: u64) -> Self {
+        assert!(modulus > 0, "A congruence needs a positive modulus");
+        return Congruence { residue: residue % modulus, modulus };
+    }
+
+    pub fn get_residue(&self) -> u64 {
+        return self.residue;
+    }
+
+    pub fn get_modulus(&self) -> u64 {
+        return self.modulus;
+    }
+
+    pub fn contains(&self, value: u64) -> bool {
+        return value % self.modulus == self.residue;
+    }
+
+    //Smallest solution that is at least the given value
+    pub fn first_at_least(&self, value: u64) -> u64 {
+        let offset = (self.residue + self.modulus - value % self.modulus) % self.modulus;
+        return value + offset;
+    }
+
+    //Chinese remainder theorem generalized to moduli that share factors. Returns the congruence holding every value
+    //that satisfies both, or None if no value does. Fails if the moduli's lcm doesn't fit in a u64.
+    pub fn combine(&self, other: &Congruence) -> Result<Option<Congruence>, String> {
+        let (m1, m2) = (self.modulus as i128, other.modulus as i128);
+        let extended = m1.extended_gcd(&m2);
+        let difference = other.residue as i128 - self.residue as i128;
+        if difference % extended.gcd != 0 {
+            return Ok(None);
+        }
+
+        let reduced_modulus = m2 / extended.gcd;
+        let Some(modulus) = m1.checked_mul(reduced_modulus).and_then(|x| u64::try_from(x).ok()) else {
+            return Err(format!("The lcm of {} and {} does not fit in a u64", self.modulus, other.modulus));
+        };
+
+        //x = a1 + m1 * k where m1 * k = a2 - a1 (mod m2), and extended.x inverts m1 / gcd modulo m2 / gcd. Both
+        //factors of k are reduced below reduced_modulus first so their product fits in a u128.
+        let k_factors = ((difference / extended.gcd).rem_euclid(reduced_modulus) as u128, extended.x.rem_euclid(reduced_modulus) as u128);
+        let k = (k_factors.0 * k_factors.1 % reduced_modulus as u128) as u64;
+        //a1 < m1 and k < m2 / gcd, so this stays below the combined modulus
+        let residue = self.residue + self.modulus * k;
+        return Ok(Some(Congruence { residue, modulus }));
+    }
+}
+
+//Combines all the congruences into one, or None if they have no common solution. An empty list gives every integer.
+//Fails if the lcm of the moduli doesn't fit in a u64.
+pub fn solve_congruences(congruences: &[Congruence]) -> Result<Option<Congruence>, String> {
+    let mut combined = Congruence::new(0, 1);
+    for congruence in congruences {
+        match combined.combine(congruence)? {
+            Some(x) => combined = x,
+            None => return Ok(None)
+        }
+    }
+
+    return Ok(Some(combined));
+}
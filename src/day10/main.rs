@@ -0,0 +1,30 @@
+use std::env;
+use sandbox::day10::AreaStrategy;
+use sandbox::day10_part2::solve_with_strategy;
+use sandbox::registry::find_solver;
+use sandbox::runner::*;
+
+const USAGE: &str = "\
+Usage: day10 [input path | -] [options]
+
+Options:
+    --strategy raycast|shoelace|scanline    How to count the enclosed tiles (default shoelace)
+
+Input is read the same way as for day10_part2.";
+
+//Runs day 10 part 2 with any of the enclosed area strategies
+fn main() {
+    let mut args = env::args().skip(1).collect::<Vec<String>>();
+    let strategy = match take_option(&mut args, "--strategy", USAGE) {
+        Some(strategy) => strategy.parse::<AreaStrategy>().unwrap_or_else(|x| exit_with_usage(&x, USAGE)),
+        None => AreaStrategy::default()
+    };
+
+    let solver = find_solver(10, 2).unwrap();
+    let input = read_positional_input(solver, &args).unwrap_or_else(|x| exit_with_usage(&x, USAGE));
+
+    match solve_with_strategy(&input, strategy) {
+        Ok(answer) => println!("The loop encloses {} tiles", answer),
+        Err(error) => exit_with_error(10, &error.to_string())
+    }
+}
@@ -0,0 +1,85 @@
+#![allow(clippy::needless_return)]
+use std::fmt;
+use std::str::FromStr;
+use crate::grid::{Grid, Position};
+
+//Ways of counting the tiles enclosed by the pipe loop. They all give the same count; ray casting is the original
+//method and the slowest.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum AreaStrategy {
+    //Four rays from every tile, using the normals of the loop's sides
+    RayCast,
+    //Area of the loop's polygon, with Pick's theorem to remove the loop's own tiles
+    #[default]
+    Shoelace,
+    //Crossing count along each row
+    Scanline
+}
+
+impl AreaStrategy {
+    pub const ALL: [AreaStrategy; 3] = [AreaStrategy::RayCast, AreaStrategy::Shoelace, AreaStrategy::Scanline];
+}
+
+impl FromStr for AreaStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return match s {
+            "raycast" => Ok(AreaStrategy::RayCast),
+            "shoelace" => Ok(AreaStrategy::Shoelace),
+            "scanline" => Ok(AreaStrategy::Scanline),
+            _ => Err(format!("Invalid strategy '{}', expected raycast, shoelace, or scanline", s))
+        };
+    }
+}
+
+impl fmt::Display for AreaStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            AreaStrategy::RayCast => write!(f, "raycast"),
+            AreaStrategy::Shoelace => write!(f, "shoelace"),
+            AreaStrategy::Scanline => write!(f, "scanline")
+        };
+    }
+}
+
+//The loop is the tiles in the order they're visited, each one next to the one before and the last next to the
+//first. Tile centers are the polygon's corners, so every loop tile is a lattice point on its boundary and Pick's
+//theorem (area = interior + boundary / 2 - 1) gives the interior tiles.
+pub fn count_enclosed_by_shoelace(path: &[Position]) -> u64 {
+    let twice_area = path
+        .iter()
+        .zip(path.iter().cycle().skip(1))
+        .map(|(a, b)| a.0 as i64 * b.1 as i64 - b.0 as i64 * a.1 as i64)
+        .sum::<i64>()
+        .unsigned_abs();
+
+    return (twice_area + 2).saturating_sub(path.len() as u64) / 2;
+}
+
+//Walks each row keeping track of whether it's inside the loop. Crossing a loop tile that connects to the tile
+//above flips the side, which counts a horizontal run like L--7 as one crossing and L--J as none.
+pub fn count_enclosed_by_scanline(path: &[Position], height: usize, width: usize) -> u64 {
+    let mut loop_tiles = Grid::new(width, height, false);
+    let mut connects_north = Grid::new(width, height, false);
+    for (index, tile) in path.iter().enumerate() {
+        let previous = path[(index + path.len() - 1) % path.len()];
+        let next = path[(index + 1) % path.len()];
+        loop_tiles[*tile] = true;
+        connects_north[*tile] = [previous, next].iter().any(|x| x.1 == tile.1 && x.0 + 1 == tile.0);
+    }
+
+    let mut num_enclosed_tiles = 0u64;
+    for row_index in 0..height {
+        let mut inside = false;
+        for col_index in 0..width {
+            if loop_tiles[(row_index, col_index)] {
+                inside ^= connects_north[(row_index, col_index)];
+            } else if inside {
+                num_enclosed_tiles += 1;
+            }
+        }
+    }
+
+    return num_enclosed_tiles;
+}
@@ -1,5 +1,5 @@
+#![allow(clippy::needless_return)]
 use GridItem::*;
-use RelativePosition::*;
 
 #[derive(PartialEq, Eq, Hash)]
 pub enum GridItem {
@@ -14,39 +14,17 @@ pub enum GridItem {
 }
 
 impl GridItem {
-    pub fn parse(symbol: char) -> Self {
+    pub fn parse(symbol: char) -> Option<Self> {
         return match symbol {
-            '|' => Vertical,
-            '-' => Horizontal,
-            'L' => RightAngleNorthEast,
-            'J' => RightAngleNorthWest,
-            '7' => RightAngleSouthWest,
-            'F' => RightAngleSouthEast,
-            '.' => Ground,
-            'S' => StartingPosition,
-            _ => panic!("Invalid symbol")
+            '|' => Some(Vertical),
+            '-' => Some(Horizontal),
+            'L' => Some(RightAngleNorthEast),
+            'J' => Some(RightAngleNorthWest),
+            '7' => Some(RightAngleSouthWest),
+            'F' => Some(RightAngleSouthEast),
+            '.' => Some(Ground),
+            'S' => Some(StartingPosition),
+            _ => None
         }
     }
-}
-
-#[derive(PartialEq, Eq, Hash)]
-pub enum RelativePosition {
-    North,
-    East,
-    South,
-    West
-}
-
-//positions = (row, col)
-pub fn get_relative_position(source: &(usize, usize), dest: &(usize, usize)) -> RelativePosition {
-    //Reversed for row direction because we are using matrix coordinate conventions
-    return if dest.0 < source.0 {
-        North
-    } else if dest.0 > source.0 {
-        South
-    } else if dest.1 < source.1 {
-        West
-    } else {
-        East
-    }
-}
+}
\ No newline at end of file
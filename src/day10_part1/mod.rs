@@ -0,0 +1,59 @@
+#![allow(clippy::needless_return)]
+use itertools::Itertools;
+
+use crate::day10_part1::grid_item::*;
+use crate::day10_part1::grid_item::GridItem::{Horizontal, RightAngleNorthEast, RightAngleNorthWest, RightAngleSouthEast, RightAngleSouthWest, Vertical};
+use crate::day10_part1::pipe_loop_solver::*;
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::direction::Direction;
+use crate::direction::Direction::*;
+use crate::error::SolverError;
+use crate::grid::Grid;
+
+pub mod grid_item;
+pub mod pipe_loop_solver;
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //Parse input data, find starting position, and update staring position with calculated grid item
+    let mut grid_map = Grid::parse(input, GridItem::parse)?;
+    end_parse_phase();
+    let starting_position = find_starting_position(&grid_map)?;
+    let starting_grid_item = calculate_starting_grid_item(&starting_position, &grid_map)?;
+    grid_map[starting_position] = starting_grid_item;
+
+    //Solve grid
+    let mut solver = Solver::new(&starting_position, &grid_map);
+    solver.solve()?;
+    return Ok(Answer::from(solver.get_path().len() / 2));
+}
+
+fn find_starting_position(grid_map: &Grid<GridItem>) -> Result<(usize, usize), SolverError> {
+    return grid_map
+        .position(|x| *x == GridItem::StartingPosition)
+        .ok_or_else(|| SolverError::invalid_input("Could not find the starting position"));
+}
+
+fn calculate_starting_grid_item(starting_position: &(usize, usize), grid_map: &Grid<GridItem>) -> Result<GridItem, SolverError> {
+    let starting_options_relative: (Direction, Direction) = get_valid_positions(starting_position, starting_position, grid_map)
+        .iter()
+        .map(|test_pos| Direction::between(starting_position, test_pos))
+        .collect_tuple()
+        .ok_or_else(|| SolverError::invalid_input("The starting position must connect to exactly two pipes"))?;
+
+    return if starting_options_relative == (North, South) || starting_options_relative == (South, North) {
+        Ok(Vertical)
+    } else if starting_options_relative == (North, East) || starting_options_relative == (East, North) {
+        Ok(RightAngleNorthEast)
+    } else if starting_options_relative == (North, West) || starting_options_relative == (West, North) {
+        Ok(RightAngleNorthWest)
+    } else if starting_options_relative == (East, South) || starting_options_relative == (South, East) {
+      Ok(RightAngleSouthEast)
+    } else if starting_options_relative == (East, West) || starting_options_relative == (West, East) {
+        Ok(Horizontal)
+    } else if starting_options_relative == (South, West) || starting_options_relative == (West, South) {
+        Ok(RightAngleSouthWest)
+    } else {
+        Err(SolverError::invalid_input("Could not determine the starting grid item type"))
+    };
+}
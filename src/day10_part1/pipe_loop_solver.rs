@@ -1,11 +1,15 @@
+#![allow(clippy::needless_return)]
 use std::collections::HashSet;
-use crate::grid_item::*;
-use crate::grid_item::GridItem::*;
-use crate::grid_item::RelativePosition::*;
+use crate::error::SolverError;
+use crate::grid::Grid;
+use crate::day10_part1::grid_item::*;
+use crate::day10_part1::grid_item::GridItem::*;
+use crate::direction::Direction;
+use crate::direction::Direction::*;
 
 pub struct Solver<'a> {
     starting_position: (usize, usize),
-    grid_map: &'a Vec<Vec<GridItem>>,
+    grid_map: &'a Grid<GridItem>,
     path: Vec<(usize, usize)>
 }
 
@@ -14,54 +18,46 @@ impl<'a> Solver<'a> {
         return &self.path;
     }
 
-    pub fn new(starting_position: &(usize, usize), grid_map: &'a Vec<Vec<GridItem>>) -> Self {
+    pub fn new(starting_position: &(usize, usize), grid_map: &'a Grid<GridItem>) -> Self {
         return Self {
-            starting_position: starting_position.clone(),
+            starting_position: *starting_position,
             grid_map,
             path: Vec::<(usize, usize)>::new(),
         };
     }
 
-    pub fn solve(&mut self) {
+    pub fn solve(&mut self) -> Result<(), SolverError> {
         self.path.push(self.starting_position);
         let mut loop_complete = false;
 
         while !loop_complete {
             let current_position = self.path.last().unwrap();
-            let prev_position = self.path.iter().nth(self.path.len().wrapping_sub(2)).unwrap_or_else(|| current_position);
-            let valid_positions = get_valid_positions(&current_position, &prev_position, self.grid_map);
-            let next_point = valid_positions.first().unwrap().clone();
+            let prev_position = self.path.get(self.path.len().wrapping_sub(2)).unwrap_or(current_position);
+            let valid_positions = get_valid_positions(current_position, prev_position, self.grid_map);
+            let Some(next_point) = valid_positions.first().copied() else {
+                return Err(SolverError::invalid_input("The loop through the starting position is broken"));
+            };
 
             loop_complete = next_point == self.starting_position;
             if !loop_complete {
                 self.path.push(next_point);
             }
         }
+
+        return Ok(());
     }
 }
 
-pub fn get_valid_positions(position: &(usize, usize), prev_position: &(usize, usize), grid_map: &Vec<Vec<GridItem>>) -> Vec<(usize, usize)> {
-    let mut test_positions = HashSet::from([
-        (position.0 - 1, position.1),
-        (position.0 + 1, position.1),
-        (position.0, position.1 - 1),
-        (position.0, position.1 + 1)
-    ]);
-    test_positions.remove(&prev_position);
-
-    let is_inbounds = |test_pos: &(usize, usize)| test_pos.0 < grid_map.len() &&
-        test_pos.1 < grid_map[0].len();
-
-    return test_positions
-        .iter()
-        .filter(|&test_pos| is_inbounds(test_pos))
-        .filter(|&test_pos| is_valid_join(position, test_pos, grid_map))
-        .map(|x| x.clone())
-        .collect::<Vec<(usize, usize)>>()
+pub fn get_valid_positions(position: &(usize, usize), prev_position: &(usize, usize), grid_map: &Grid<GridItem>) -> Vec<(usize, usize)> {
+    return grid_map
+        .neighbors4(*position)
+        .filter(|test_pos| test_pos != prev_position)
+        .filter(|test_pos| is_valid_join(position, test_pos, grid_map))
+        .collect::<Vec<(usize, usize)>>();
 }
 
-pub fn is_valid_join(position: &(usize, usize), test_pos: &(usize, usize), grid_map: &Vec<Vec<GridItem>>) -> bool {
-    let valid_combinations: HashSet<(&GridItem, &GridItem, &RelativePosition)> = HashSet::from(
+pub fn is_valid_join(position: &(usize, usize), test_pos: &(usize, usize), grid_map: &Grid<GridItem>) -> bool {
+    let valid_combinations: HashSet<(&GridItem, &GridItem, &Direction)> = HashSet::from(
         [(&Vertical, &Vertical, &North),
             (&Vertical, &Vertical, &South),
             (&Vertical, &RightAngleNorthEast, &South),
@@ -112,9 +108,9 @@ pub fn is_valid_join(position: &(usize, usize), test_pos: &(usize, usize), grid_
             (&StartingPosition, &RightAngleSouthEast, &West)
         ]);
 
-    let current_grid_item = &grid_map[position.0][position.1];
-    let test_grid_item = &grid_map[test_pos.0][test_pos.1];
-    let relative_position = get_relative_position(position, test_pos);
+    let current_grid_item = &grid_map[*position];
+    let test_grid_item = &grid_map[*test_pos];
+    let relative_position = Direction::between(position, test_pos);
     let is_valid = valid_combinations.contains(&(current_grid_item, test_grid_item, &relative_position));
 
     return is_valid;
@@ -1,5 +1,7 @@
+#![allow(clippy::needless_return)]
 use GridItem::*;
-use RelativePosition::*;
+use crate::direction::{CompassDirection, Direction};
+use crate::direction::Direction::*;
 use std::collections::{HashSet, HashMap};
 use lazy_static::lazy_static;
 
@@ -16,59 +18,24 @@ pub enum GridItem {
 }
 
 impl GridItem {
-    pub fn parse(symbol: char) -> Self {
+    pub fn parse(symbol: char) -> Option<Self> {
         return match symbol {
-            '|' => Vertical,
-            '-' => Horizontal,
-            'L' => RightAngleNorthEast,
-            'J' => RightAngleNorthWest,
-            '7' => RightAngleSouthWest,
-            'F' => RightAngleSouthEast,
-            '.' => Ground,
-            'S' => StartingPosition,
-            _ => panic!("Invalid symbol")
+            '|' => Some(Vertical),
+            '-' => Some(Horizontal),
+            'L' => Some(RightAngleNorthEast),
+            'J' => Some(RightAngleNorthWest),
+            '7' => Some(RightAngleSouthWest),
+            'F' => Some(RightAngleSouthEast),
+            '.' => Some(Ground),
+            'S' => Some(StartingPosition),
+            _ => None
         }
     }
 }
 
-#[derive(PartialEq, Eq, Hash, Debug)]
-pub enum RelativePosition {
-    North,
-    East,
-    South,
-    West
-}
-
-//With convention that the normal points to interior of the pipe area
-#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
-pub enum NormalDirection {
-    North,
-    Northeast,
-    East,
-    Southeast,
-    South,
-    Southwest,
-    West,
-    Northwest,
-    Unknown
-}
-
-//positions = (row, col)
-pub fn get_relative_position(source: &(usize, usize), dest: &(usize, usize)) -> RelativePosition {
-    //Reversed for row direction because we are using matrix coordinate conventions
-    return if dest.0 < source.0 {
-        North
-    } else if dest.0 > source.0 {
-        South
-    } else if dest.1 < source.1 {
-        West
-    } else {
-        East
-    }
-}
 
 lazy_static! {
-    pub static ref VALID_JOIN_COMINATIONS: HashSet <(&'static GridItem, &'static GridItem, &'static RelativePosition)> = HashSet::from([
+    pub static ref VALID_JOIN_COMINATIONS: HashSet <(&'static GridItem, &'static GridItem, &'static Direction)> = HashSet::from([
         (&Vertical, &Vertical, &North),
         (&Vertical, &Vertical, &South),
         (&Vertical, &RightAngleNorthEast, &South),
@@ -121,71 +88,71 @@ lazy_static! {
 }
 
 lazy_static! {
-    pub static ref NORMAL_LOOKUP_TABLE: HashMap<(&'static GridItem, &'static GridItem, &'static NormalDirection), &'static NormalDirection> = HashMap::from([
-        ((&Vertical, &Vertical, &NormalDirection::West), &NormalDirection::West),
-        ((&Vertical, &Vertical, &NormalDirection::East), &NormalDirection::East),
-        ((&Vertical, &RightAngleNorthEast, &NormalDirection::West), &NormalDirection::Southwest),
-        ((&Vertical, &RightAngleNorthEast, &NormalDirection::East), &NormalDirection::Northeast),
-        ((&Vertical, &RightAngleSouthEast, &NormalDirection::West), &NormalDirection::Northwest),
-        ((&Vertical, &RightAngleSouthEast, &NormalDirection::East), &NormalDirection::Southeast),
-        ((&Vertical, &RightAngleSouthWest, &NormalDirection::West), &NormalDirection::Southwest),
-        ((&Vertical, &RightAngleSouthWest, &NormalDirection::East), &NormalDirection::Northeast),
-        ((&Vertical, &RightAngleNorthWest, &NormalDirection::West), &NormalDirection::Northwest),
-        ((&Vertical, &RightAngleNorthWest, &NormalDirection::East), &NormalDirection::Southeast),
+    pub static ref NORMAL_LOOKUP_TABLE: HashMap<(&'static GridItem, &'static GridItem, &'static CompassDirection), &'static CompassDirection> = HashMap::from([
+        ((&Vertical, &Vertical, &CompassDirection::West), &CompassDirection::West),
+        ((&Vertical, &Vertical, &CompassDirection::East), &CompassDirection::East),
+        ((&Vertical, &RightAngleNorthEast, &CompassDirection::West), &CompassDirection::Southwest),
+        ((&Vertical, &RightAngleNorthEast, &CompassDirection::East), &CompassDirection::Northeast),
+        ((&Vertical, &RightAngleSouthEast, &CompassDirection::West), &CompassDirection::Northwest),
+        ((&Vertical, &RightAngleSouthEast, &CompassDirection::East), &CompassDirection::Southeast),
+        ((&Vertical, &RightAngleSouthWest, &CompassDirection::West), &CompassDirection::Southwest),
+        ((&Vertical, &RightAngleSouthWest, &CompassDirection::East), &CompassDirection::Northeast),
+        ((&Vertical, &RightAngleNorthWest, &CompassDirection::West), &CompassDirection::Northwest),
+        ((&Vertical, &RightAngleNorthWest, &CompassDirection::East), &CompassDirection::Southeast),
 
-        ((&Horizontal, &Horizontal, &NormalDirection::North), &NormalDirection::North),
-        ((&Horizontal, &Horizontal, &NormalDirection::South), &NormalDirection::South),
-        ((&Horizontal, &RightAngleNorthEast, &NormalDirection::North), &NormalDirection::Northeast),
-        ((&Horizontal, &RightAngleNorthEast, &NormalDirection::South), &NormalDirection::Southwest),
-        ((&Horizontal, &RightAngleSouthEast, &NormalDirection::North), &NormalDirection::Northwest),
-        ((&Horizontal, &RightAngleSouthEast, &NormalDirection::South), &NormalDirection::Southeast),
-        ((&Horizontal, &RightAngleSouthWest, &NormalDirection::North), &NormalDirection::Northeast),
-        ((&Horizontal, &RightAngleSouthWest, &NormalDirection::South), &NormalDirection::Southwest),
-        ((&Horizontal, &RightAngleNorthWest, &NormalDirection::North), &NormalDirection::Northwest),
-        ((&Horizontal, &RightAngleNorthWest, &NormalDirection::South), &NormalDirection::Southeast),
+        ((&Horizontal, &Horizontal, &CompassDirection::North), &CompassDirection::North),
+        ((&Horizontal, &Horizontal, &CompassDirection::South), &CompassDirection::South),
+        ((&Horizontal, &RightAngleNorthEast, &CompassDirection::North), &CompassDirection::Northeast),
+        ((&Horizontal, &RightAngleNorthEast, &CompassDirection::South), &CompassDirection::Southwest),
+        ((&Horizontal, &RightAngleSouthEast, &CompassDirection::North), &CompassDirection::Northwest),
+        ((&Horizontal, &RightAngleSouthEast, &CompassDirection::South), &CompassDirection::Southeast),
+        ((&Horizontal, &RightAngleSouthWest, &CompassDirection::North), &CompassDirection::Northeast),
+        ((&Horizontal, &RightAngleSouthWest, &CompassDirection::South), &CompassDirection::Southwest),
+        ((&Horizontal, &RightAngleNorthWest, &CompassDirection::North), &CompassDirection::Northwest),
+        ((&Horizontal, &RightAngleNorthWest, &CompassDirection::South), &CompassDirection::Southeast),
 
-        ((&RightAngleNorthEast, &Horizontal, &NormalDirection::Northeast), &NormalDirection::North),
-        ((&RightAngleNorthEast, &Horizontal, &NormalDirection::Southwest), &NormalDirection::South),
-        ((&RightAngleNorthEast, &Vertical, &NormalDirection::Northeast), &NormalDirection::East),
-        ((&RightAngleNorthEast, &Vertical, &NormalDirection::Southwest), &NormalDirection::West),
-        ((&RightAngleNorthEast, &RightAngleSouthEast, &NormalDirection::Northeast), &NormalDirection::Southeast),
-        ((&RightAngleNorthEast, &RightAngleSouthEast, &NormalDirection::Southwest), &NormalDirection::Northwest),
-        ((&RightAngleNorthEast, &RightAngleSouthWest, &NormalDirection::Northeast), &NormalDirection::Northeast),
-        ((&RightAngleNorthEast, &RightAngleSouthWest, &NormalDirection::Southwest), &NormalDirection::Southwest),
-        ((&RightAngleNorthEast, &RightAngleNorthWest, &NormalDirection::Northeast), &NormalDirection::Northwest),
-        ((&RightAngleNorthEast, &RightAngleNorthWest, &NormalDirection::Southwest), &NormalDirection::Southeast),
+        ((&RightAngleNorthEast, &Horizontal, &CompassDirection::Northeast), &CompassDirection::North),
+        ((&RightAngleNorthEast, &Horizontal, &CompassDirection::Southwest), &CompassDirection::South),
+        ((&RightAngleNorthEast, &Vertical, &CompassDirection::Northeast), &CompassDirection::East),
+        ((&RightAngleNorthEast, &Vertical, &CompassDirection::Southwest), &CompassDirection::West),
+        ((&RightAngleNorthEast, &RightAngleSouthEast, &CompassDirection::Northeast), &CompassDirection::Southeast),
+        ((&RightAngleNorthEast, &RightAngleSouthEast, &CompassDirection::Southwest), &CompassDirection::Northwest),
+        ((&RightAngleNorthEast, &RightAngleSouthWest, &CompassDirection::Northeast), &CompassDirection::Northeast),
+        ((&RightAngleNorthEast, &RightAngleSouthWest, &CompassDirection::Southwest), &CompassDirection::Southwest),
+        ((&RightAngleNorthEast, &RightAngleNorthWest, &CompassDirection::Northeast), &CompassDirection::Northwest),
+        ((&RightAngleNorthEast, &RightAngleNorthWest, &CompassDirection::Southwest), &CompassDirection::Southeast),
 
-        ((&RightAngleSouthEast, &Horizontal, &NormalDirection::Southeast), &NormalDirection::South),
-        ((&RightAngleSouthEast, &Horizontal, &NormalDirection::Northwest), &NormalDirection::North),
-        ((&RightAngleSouthEast, &Vertical, &NormalDirection::Southeast), &NormalDirection::East),
-        ((&RightAngleSouthEast, &Vertical, &NormalDirection::Northwest), &NormalDirection::West),
-        ((&RightAngleSouthEast, &RightAngleNorthEast, &NormalDirection::Southeast), &NormalDirection::Northeast),
-        ((&RightAngleSouthEast, &RightAngleNorthEast, &NormalDirection::Northwest), &NormalDirection::Southwest),
-        ((&RightAngleSouthEast, &RightAngleSouthWest, &NormalDirection::Southeast), &NormalDirection::Southwest),
-        ((&RightAngleSouthEast, &RightAngleSouthWest, &NormalDirection::Northwest), &NormalDirection::Northeast),
-        ((&RightAngleSouthEast, &RightAngleNorthWest, &NormalDirection::Southeast), &NormalDirection::Southeast),
-        ((&RightAngleSouthEast, &RightAngleNorthWest, &NormalDirection::Northwest), &NormalDirection::Northwest),
+        ((&RightAngleSouthEast, &Horizontal, &CompassDirection::Southeast), &CompassDirection::South),
+        ((&RightAngleSouthEast, &Horizontal, &CompassDirection::Northwest), &CompassDirection::North),
+        ((&RightAngleSouthEast, &Vertical, &CompassDirection::Southeast), &CompassDirection::East),
+        ((&RightAngleSouthEast, &Vertical, &CompassDirection::Northwest), &CompassDirection::West),
+        ((&RightAngleSouthEast, &RightAngleNorthEast, &CompassDirection::Southeast), &CompassDirection::Northeast),
+        ((&RightAngleSouthEast, &RightAngleNorthEast, &CompassDirection::Northwest), &CompassDirection::Southwest),
+        ((&RightAngleSouthEast, &RightAngleSouthWest, &CompassDirection::Southeast), &CompassDirection::Southwest),
+        ((&RightAngleSouthEast, &RightAngleSouthWest, &CompassDirection::Northwest), &CompassDirection::Northeast),
+        ((&RightAngleSouthEast, &RightAngleNorthWest, &CompassDirection::Southeast), &CompassDirection::Southeast),
+        ((&RightAngleSouthEast, &RightAngleNorthWest, &CompassDirection::Northwest), &CompassDirection::Northwest),
 
-        ((&RightAngleSouthWest, &Horizontal, &NormalDirection::Southwest), &NormalDirection::South),
-        ((&RightAngleSouthWest, &Horizontal, &NormalDirection::Northeast), &NormalDirection::North),
-        ((&RightAngleSouthWest, &Vertical, &NormalDirection::Southwest), &NormalDirection::West),
-        ((&RightAngleSouthWest, &Vertical, &NormalDirection::Northeast), &NormalDirection::East),
-        ((&RightAngleSouthWest, &RightAngleNorthEast, &NormalDirection::Southwest), &NormalDirection::Southwest),
-        ((&RightAngleSouthWest, &RightAngleNorthEast, &NormalDirection::Northeast), &NormalDirection::Northeast),
-        ((&RightAngleSouthWest, &RightAngleSouthEast, &NormalDirection::Southwest), &NormalDirection::Southeast),
-        ((&RightAngleSouthWest, &RightAngleSouthEast, &NormalDirection::Northeast), &NormalDirection::Northwest),
-        ((&RightAngleSouthWest, &RightAngleNorthWest, &NormalDirection::Southwest), &NormalDirection::Northwest),
-        ((&RightAngleSouthWest, &RightAngleNorthWest, &NormalDirection::Northeast), &NormalDirection::Southeast),
+        ((&RightAngleSouthWest, &Horizontal, &CompassDirection::Southwest), &CompassDirection::South),
+        ((&RightAngleSouthWest, &Horizontal, &CompassDirection::Northeast), &CompassDirection::North),
+        ((&RightAngleSouthWest, &Vertical, &CompassDirection::Southwest), &CompassDirection::West),
+        ((&RightAngleSouthWest, &Vertical, &CompassDirection::Northeast), &CompassDirection::East),
+        ((&RightAngleSouthWest, &RightAngleNorthEast, &CompassDirection::Southwest), &CompassDirection::Southwest),
+        ((&RightAngleSouthWest, &RightAngleNorthEast, &CompassDirection::Northeast), &CompassDirection::Northeast),
+        ((&RightAngleSouthWest, &RightAngleSouthEast, &CompassDirection::Southwest), &CompassDirection::Southeast),
+        ((&RightAngleSouthWest, &RightAngleSouthEast, &CompassDirection::Northeast), &CompassDirection::Northwest),
+        ((&RightAngleSouthWest, &RightAngleNorthWest, &CompassDirection::Southwest), &CompassDirection::Northwest),
+        ((&RightAngleSouthWest, &RightAngleNorthWest, &CompassDirection::Northeast), &CompassDirection::Southeast),
 
-        ((&RightAngleNorthWest, &Horizontal, &NormalDirection::Northwest), &NormalDirection::North),
-        ((&RightAngleNorthWest, &Horizontal, &NormalDirection::Southeast), &NormalDirection::South),
-        ((&RightAngleNorthWest, &Vertical, &NormalDirection::Northwest), &NormalDirection::West),
-        ((&RightAngleNorthWest, &Vertical, &NormalDirection::Southeast), &NormalDirection::East),
-        ((&RightAngleNorthWest, &RightAngleNorthEast, &NormalDirection::Northwest), &NormalDirection::Northeast),
-        ((&RightAngleNorthWest, &RightAngleNorthEast, &NormalDirection::Southeast), &NormalDirection::Southwest),
-        ((&RightAngleNorthWest, &RightAngleSouthEast, &NormalDirection::Northwest), &NormalDirection::Northwest),
-        ((&RightAngleNorthWest, &RightAngleSouthEast, &NormalDirection::Southeast), &NormalDirection::Southeast),
-        ((&RightAngleNorthWest, &RightAngleSouthWest, &NormalDirection::Northwest), &NormalDirection::Southwest),
-        ((&RightAngleNorthWest, &RightAngleSouthWest, &NormalDirection::Southeast), &NormalDirection::Northeast),
+        ((&RightAngleNorthWest, &Horizontal, &CompassDirection::Northwest), &CompassDirection::North),
+        ((&RightAngleNorthWest, &Horizontal, &CompassDirection::Southeast), &CompassDirection::South),
+        ((&RightAngleNorthWest, &Vertical, &CompassDirection::Northwest), &CompassDirection::West),
+        ((&RightAngleNorthWest, &Vertical, &CompassDirection::Southeast), &CompassDirection::East),
+        ((&RightAngleNorthWest, &RightAngleNorthEast, &CompassDirection::Northwest), &CompassDirection::Northeast),
+        ((&RightAngleNorthWest, &RightAngleNorthEast, &CompassDirection::Southeast), &CompassDirection::Southwest),
+        ((&RightAngleNorthWest, &RightAngleSouthEast, &CompassDirection::Northwest), &CompassDirection::Northwest),
+        ((&RightAngleNorthWest, &RightAngleSouthEast, &CompassDirection::Southeast), &CompassDirection::Southeast),
+        ((&RightAngleNorthWest, &RightAngleSouthWest, &CompassDirection::Northwest), &CompassDirection::Southwest),
+        ((&RightAngleNorthWest, &RightAngleSouthWest, &CompassDirection::Southeast), &CompassDirection::Northeast),
         ]);
 }
@@ -0,0 +1,126 @@
+#![allow(clippy::needless_return)]
+use std::collections::{HashSet, HashMap};
+use itertools::Itertools;
+
+use crate::day10_part2::grid_item::*;
+use crate::day10_part2::grid_item::GridItem::{Horizontal, RightAngleNorthEast, RightAngleNorthWest, RightAngleSouthEast, RightAngleSouthWest, Vertical};
+use crate::day10_part2::pipe_loop_solver::*;
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::day10::*;
+use crate::direction::{CompassDirection, Direction};
+use crate::direction::Direction::*;
+use crate::error::SolverError;
+use crate::grid::Grid;
+
+pub mod grid_item;
+pub mod pipe_loop_solver;
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    return solve_with_strategy(input, AreaStrategy::default());
+}
+
+pub fn solve_with_strategy(input: &str, strategy: AreaStrategy) -> Result<Answer, SolverError> {
+    //Parse input data, find starting position, and update staring position with calculated grid item
+    let mut grid_map = Grid::parse(input, GridItem::parse)?;
+    end_parse_phase();
+    let starting_position = find_starting_position(&grid_map)?;
+    let starting_grid_item = calculate_starting_grid_item(&starting_position, &grid_map)?;
+    grid_map[starting_position] = starting_grid_item;
+
+    //Solve grid
+    let mut solver = Solver::new(&starting_position, &grid_map);
+    solver.solve()?;
+
+    //Calculate final solution
+    let num_encircled_tiles = match strategy {
+        AreaStrategy::RayCast => count_enclosed_by_ray_casting(&mut solver, &grid_map)?,
+        AreaStrategy::Shoelace => count_enclosed_by_shoelace(solver.get_path()),
+        AreaStrategy::Scanline => count_enclosed_by_scanline(solver.get_path(), grid_map.get_height(), grid_map.get_width())
+    };
+    return Ok(Answer::from(num_encircled_tiles));
+}
+
+fn count_enclosed_by_ray_casting(solver: &mut Solver, grid_map: &GridMatrix) -> Result<u64, SolverError> {
+    solver.calc_path_normals()?;
+
+    //Put data in hash tables for next step
+    let mut path_set = HashSet::<Point>::new();
+    for path_point in solver.get_path() {
+        path_set.insert(*path_point);
+    }
+    let mut path_normals_map = HashMap::<Point, Option<CompassDirection>>::new();
+    for index in 0..solver.get_path().len() {
+        path_normals_map.insert(solver.get_path()[index], solver.get_path_normals()[index]);
+    }
+
+    return Ok(calc_num_encircled_tiles(&path_set, &path_normals_map, grid_map.get_height(), grid_map.get_width()));
+}
+
+fn find_starting_position(grid_map: &GridMatrix) -> Result<Point, SolverError> {
+    return grid_map
+        .position(|x| *x == GridItem::StartingPosition)
+        .ok_or_else(|| SolverError::invalid_input("Could not find the starting position"));
+}
+
+fn calculate_starting_grid_item(starting_position: &Point, grid_map: &GridMatrix) -> Result<GridItem, SolverError> {
+    let starting_options_relative: (Direction, Direction) = get_valid_positions(starting_position, starting_position, grid_map)
+        .iter()
+        .map(|test_pos| Direction::between(starting_position, test_pos))
+        .collect_tuple()
+        .ok_or_else(|| SolverError::invalid_input("The starting position must connect to exactly two pipes"))?;
+
+    return if starting_options_relative == (North, South) || starting_options_relative == (South, North) {
+        Ok(Vertical)
+    } else if starting_options_relative == (North, East) || starting_options_relative == (East, North) {
+        Ok(RightAngleNorthEast)
+    } else if starting_options_relative == (North, West) || starting_options_relative == (West, North) {
+        Ok(RightAngleNorthWest)
+    } else if starting_options_relative == (East, South) || starting_options_relative == (South, East) {
+      Ok(RightAngleSouthEast)
+    } else if starting_options_relative == (East, West) || starting_options_relative == (West, East) {
+        Ok(Horizontal)
+    } else if starting_options_relative == (South, West) || starting_options_relative == (West, South) {
+        Ok(RightAngleSouthWest)
+    } else {
+        Err(SolverError::invalid_input("Could not determine the starting grid item type"))
+    };
+}
+
+fn calc_num_encircled_tiles(path_set: &HashSet<Point>, path_normals_map: &HashMap<Point, Option<CompassDirection>>, grid_row_max: usize, grid_col_max: usize) -> u64 {
+    let mut num_encircled_tiles = 0u64;
+
+    for row_index in 0..grid_row_max {
+        for col_index in 0..grid_col_max {
+            let test_point = &(row_index, col_index);
+
+            if !path_set.contains(test_point) && Direction::ALL
+                .iter()
+                .all(|&x| ray_bounded_in_path(test_point, x, path_set, path_normals_map, grid_row_max, grid_col_max)) {
+                num_encircled_tiles += 1;
+            }
+        }
+    }
+
+    return num_encircled_tiles;
+}
+
+fn ray_bounded_in_path(ray_origin: &Point, direction: Direction, path_set: &HashSet<Point>, path_normals_map: &HashMap<Point, Option<CompassDirection>>, grid_row_max: usize, grid_col_max: usize) -> bool {
+    let point_iter: Box<dyn Iterator<Item = (usize, usize)>> = match direction {
+        North => Box::new((0..ray_origin.0 + 1).rev().map(|row_index| (row_index, ray_origin.1))),
+        East => Box::new((ray_origin.1..grid_col_max + 1).map(|col_index| (ray_origin.0, col_index))),
+        South => Box::new((ray_origin.0..grid_row_max + 1).map(|row_index| (row_index, ray_origin.1))),
+        West => Box::new((0..ray_origin.1 + 1).rev().map(|col_index| (ray_origin.0, col_index))),
+    };
+
+    //The origin is inside the loop if the first side the ray hits has its normal pointing back towards the origin
+    let mut contained = false;
+    for point in point_iter {
+        if path_set.contains(&point) {
+            contained = path_normals_map[&point].is_some_and(|x| x.points_towards(direction.reverse()));
+            break;
+        }
+    }
+
+    return contained;
+}
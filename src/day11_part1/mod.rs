@@ -0,0 +1,82 @@
+#![allow(clippy::needless_return, clippy::ptr_arg)]
+use std::convert::TryInto;
+use itertools::Itertools;
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::error::SolverError;
+use crate::grid::Grid;
+
+type Point = (usize, usize);
+const EMPTY_SYMBOL: char = '.';
+const GALAXY_SYMBOL: char = '#';
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //Read input
+    let mut data = parse_data(input)?;
+    end_parse_phase();
+
+    //Find empty regions and insert extra space
+    let empty_rows = find_empty_rows(&data);
+    let empty_cols = find_empty_cols(&data);
+    add_extra_rows(&mut data, &empty_rows);
+    add_extra_cols(&mut data, &empty_cols);
+
+    //Find galaxy positions and calculate distances
+    let galaxy_positions = find_galaxies(&data);
+    if galaxy_positions.is_empty() {
+        return Err(SolverError::invalid_input("There are no galaxies in the image"));
+    }
+    let mut galaxy_pair_distances: Vec<u32> = vec![];
+
+    for i in 0..galaxy_positions.len() - 1 {
+        for j in i+1..galaxy_positions.len() {
+            galaxy_pair_distances.push(calculate_distance(&galaxy_positions[i], &galaxy_positions[j]));
+        }
+    }
+    return Ok(Answer::from(galaxy_pair_distances.iter().sum::<u32>()));
+}
+
+fn parse_data(input: &str) -> Result<Grid<char>, SolverError> {
+    return Grid::parse(input, |x| (x == EMPTY_SYMBOL || x == GALAXY_SYMBOL).then_some(x));
+}
+
+fn find_empty_rows(image: &Grid<char>) -> Vec<usize> {
+    return image
+        .rows()
+        .positions(|x| !x.contains(&GALAXY_SYMBOL))
+        .collect::<Vec<usize>>();
+}
+
+fn find_empty_cols(image: &Grid<char>) -> Vec<usize> {
+    return image
+        .columns()
+        .positions(|mut x| x.all(|&y| y != GALAXY_SYMBOL))
+        .collect::<Vec<usize>>();
+}
+
+//Assumes empty row indices sorted
+fn add_extra_rows(image: &mut Grid<char>, empty_row_indices: &Vec<usize>) {
+    for (row_offset, empty_row_index) in empty_row_indices.iter().enumerate() {
+        image.insert_row(empty_row_index + row_offset, EMPTY_SYMBOL);
+    }
+}
+
+//Assumes empty col indices sorted
+fn add_extra_cols(image: &mut Grid<char>, empty_col_indices: &Vec<usize>) {
+    for (col_offset, empty_col_index) in empty_col_indices.iter().enumerate() {
+        image.insert_column(empty_col_index + col_offset, EMPTY_SYMBOL);
+    }
+}
+
+fn find_galaxies(image: &Grid<char>) -> Vec<Point> {
+    return image
+        .iter()
+        .filter(|(_, &x)| x == GALAXY_SYMBOL)
+        .map(|(position, _)| position)
+        .collect::<Vec<Point>>();
+}
+
+fn calculate_distance(galaxy1_pos: &Point, galaxy2_pos: &Point) -> u32 {
+
+    return (galaxy2_pos.0.abs_diff(galaxy1_pos.0) + galaxy2_pos.1.abs_diff(galaxy1_pos.1)).try_into().unwrap();
+}
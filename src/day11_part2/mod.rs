@@ -0,0 +1,98 @@
+#![allow(clippy::needless_return)]
+use std::convert::TryInto;
+use std::collections::{HashSet, HashMap};
+use itertools::Itertools;
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::error::SolverError;
+use crate::grid::Grid;
+
+type Point = (usize, usize);
+const EMPTY_SYMBOL: char = '.';
+const GALAXY_SYMBOL: char = '#';
+const EMPTY_SPACE_MULTIPLIER: usize = 1_000_000;
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //Read input
+    let data = parse_data(input)?;
+    end_parse_phase();
+
+    //Find galaxies and empty regions. Hash empty regions for next step
+    let galaxy_positions = find_galaxies(&data);
+    if galaxy_positions.is_empty() {
+        return Err(SolverError::invalid_input("There are no galaxies in the image"));
+    }
+    let empty_rows = find_empty_rows(&data);
+    let empty_cols = find_empty_cols(&data);
+    let empty_rows_set = HashSet::<usize>::from_iter(empty_rows);
+    let empty_cols_set = HashSet::<usize>::from_iter(empty_cols);
+
+    //Generate expansion maps
+    let max_row_index = data.get_height() - 1;
+    let max_col_index = data.get_width() - 1;
+    let expanded_row_map = generate_normal_to_expanded_map(&empty_rows_set, max_row_index);
+    let expanded_col_map = generate_normal_to_expanded_map(&empty_cols_set, max_col_index);
+
+    //Calculate distances
+    let mut galaxy_pair_distances: Vec<u64> = vec![];
+
+    for i in 0..galaxy_positions.len() - 1 {
+        for j in i+1..galaxy_positions.len() {
+            galaxy_pair_distances.push(calculate_distance(&galaxy_positions[i], &galaxy_positions[j],
+                &expanded_row_map, &expanded_col_map));
+        }
+    }
+    return Ok(Answer::from(galaxy_pair_distances.iter().sum::<u64>()));
+}
+
+fn parse_data(input: &str) -> Result<Grid<char>, SolverError> {
+    return Grid::parse(input, |x| (x == EMPTY_SYMBOL || x == GALAXY_SYMBOL).then_some(x));
+}
+
+fn find_galaxies(image: &Grid<char>) -> Vec<Point> {
+    return image
+        .iter()
+        .filter(|(_, &x)| x == GALAXY_SYMBOL)
+        .map(|(position, _)| position)
+        .collect::<Vec<Point>>();
+}
+
+fn find_empty_rows(image: &Grid<char>) -> Vec<usize> {
+    return image
+        .rows()
+        .positions(|x| !x.contains(&GALAXY_SYMBOL))
+        .collect::<Vec<usize>>();
+}
+
+fn find_empty_cols(image: &Grid<char>) -> Vec<usize> {
+    return image
+        .columns()
+        .positions(|mut x| x.all(|&y| y != GALAXY_SYMBOL))
+        .collect::<Vec<usize>>();
+}
+
+fn generate_normal_to_expanded_map(empty_normal_indices: &HashSet<usize>, max_normal_index: usize) -> HashMap<usize, usize> {
+    let mut expanded_dimension_map = HashMap::<usize, usize>::new();
+    let mut cumulative_offset = 0usize;
+
+    for normal_index in 0..=max_normal_index {
+        if empty_normal_indices.contains(&normal_index) {
+            cumulative_offset += EMPTY_SPACE_MULTIPLIER - 1;
+        }
+
+        expanded_dimension_map.insert(normal_index, normal_index + cumulative_offset);
+    }
+
+    return expanded_dimension_map;
+}
+
+fn calculate_distance(galaxy1_pos_normal: &Point, galaxy2_pos_normal: &Point, row_extension_map: &HashMap<usize, usize>,
+                      col_extension_map: &HashMap<usize, usize>) -> u64 {
+
+    let galaxy1_row_expanded = row_extension_map[&galaxy1_pos_normal.0];
+    let galaxy1_col_expanded = col_extension_map[&galaxy1_pos_normal.1];
+    let galaxy2_row_expanded = row_extension_map[&galaxy2_pos_normal.0];
+    let galaxy2_col_expanded = col_extension_map[&galaxy2_pos_normal.1];
+
+   return (galaxy2_row_expanded.abs_diff(galaxy1_row_expanded) + galaxy2_col_expanded.abs_diff(galaxy1_col_expanded)).try_into().unwrap();
+}
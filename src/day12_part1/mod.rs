@@ -0,0 +1,136 @@
+#![allow(clippy::needless_return, clippy::ptr_arg)]
+use itertools::{Itertools};
+use std::collections::HashSet;
+use rayon::prelude::*;
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::error::*;
+
+const NORMAL: char = '.';
+const BROKEN: char = '#';
+const UNKNOWN: char = '?';
+const LENGTHS_SEPARATOR: char = ',';
+
+struct LineData {
+    status: String,
+    continuous_broken_lengths: Vec<usize>,
+    unknown_indices: Vec<usize>
+}
+
+impl LineData {
+    fn get_status(&self) -> &String {
+        return &self.status;
+    }
+
+    #[allow(dead_code)]
+    fn get_continuous_broken_lengths(&self) -> &Vec<usize> {
+        return &self.continuous_broken_lengths;
+    }
+
+    fn get_unknown_indices(&self) -> &Vec<usize> {
+        return &self.unknown_indices;
+    }
+
+    fn new(status: String, continuous_broken_lengths: Vec<usize>) -> Self {
+        let unknown_indices = find_unknown_indices(&status);
+        return Self {status, continuous_broken_lengths, unknown_indices};
+    }
+}
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //Parse data and calculated derived data
+    let all_data = parse_data(input)?;
+    end_parse_phase();
+
+    //Calculate result
+    let match_sums = all_data
+        .par_iter()
+        .map(calc_num_matches)
+        .sum::<usize>();
+
+    return Ok(Answer::from(match_sums));
+}
+
+fn parse_data(input: &str) -> Result<Vec<LineData>, SolverError> {
+    let mut all_data = Vec::<LineData>::new();
+    for (line_index, line) in input.lines().map(String::from).enumerate() {
+        let (status, continuous_broken_lengths) = parse_line(line_index, &line)?;
+        let status = status.to_string();
+
+        all_data.push(LineData::new(status, continuous_broken_lengths));
+    }
+
+    return Ok(all_data);
+}
+
+fn parse_line(line_index: usize, line: &str) -> Result<(&str, Vec<usize>), SolverError> {
+    let Some((status, lengths)) = line.split_once(" ") else {
+        return Err(SolverError::parse_line(line_index, line, "Expected the spring status followed by the group lengths"));
+    };
+    if let Some(col_index) = status.find(|x| x != NORMAL && x != BROKEN && x != UNKNOWN) {
+        return Err(SolverError::parse_char(line_index, line, col_index, "Invalid spring status"));
+    }
+
+    let continuous_broken_lengths = lengths
+        .split(LENGTHS_SEPARATOR)
+        .map(|x| parse_token::<usize>(line_index, line, x))
+        .collect::<Result<Vec<usize>, SolverError>>()?;
+
+    return Ok((status, continuous_broken_lengths));
+}
+
+fn find_unknown_indices(status_string: &String) -> Vec<usize> {
+    return status_string
+        .chars()
+        .enumerate()
+        .filter(|(_, c)| *c == UNKNOWN)
+        .map(|(i, _)| i)
+        .collect_vec();
+}
+
+fn generate_boolean_vector_permutations(size: usize) -> Vec<Vec<bool>> {
+    let base_case = vec![true, false];
+    return (0..size)
+        .map(|_| base_case.clone())
+        .multi_cartesian_product()
+        .collect_vec();
+}
+
+fn generate_test_string(test_case: &Vec<bool>, unknown_indices: &Vec<usize>, line: &String) -> String {
+    let mut test_string_builder = Vec::<char>::new();
+    let unknown_indices_set = HashSet::<usize>::from_iter(unknown_indices.clone());
+    let mut test_case_iter = test_case.iter();
+
+    for i in 0..line.len() {
+        let value = if unknown_indices_set.contains(&i) {
+            if *test_case_iter.next().unwrap() {NORMAL} else {BROKEN}
+        } else {
+            line.chars().nth(i).unwrap()
+        };
+
+        test_string_builder.push(value)
+    }
+
+    return String::from_iter(test_string_builder);
+}
+
+fn calc_num_matches(line_data: &LineData) -> usize {
+    let test_case_is_match = |test_case| -> bool {
+        let test_string = generate_test_string(&test_case, line_data.get_unknown_indices(), line_data.get_status());
+        let test_continuous_broken_lengths = test_string
+            .split(&NORMAL.to_string())
+            .filter(|x| !x.is_empty())
+            .map(|x| x.len())
+            .collect_vec();
+
+        return test_continuous_broken_lengths == line_data.continuous_broken_lengths;
+    };
+
+    let matches = generate_boolean_vector_permutations(line_data.get_unknown_indices().len())
+        .into_iter()
+        .map(test_case_is_match)
+        .filter(|x| *x)
+        .collect_vec();
+
+    return matches.len();
+}
@@ -1,14 +1,15 @@
+#![allow(clippy::needless_return)]
 pub struct LineData {
     status: String,
     continuous_broken_lengths: Vec<usize>,
 }
 
 impl LineData {
-    pub fn get_status<'a>(&'a self) -> &'a String {
+    pub fn get_status(&self) -> &String {
         return &self.status;
     }
 
-    pub fn get_continuous_broken_lengths<'a>(&'a self) -> &'a Vec<usize> {
+    pub fn get_continuous_broken_lengths(&self) -> &Vec<usize> {
         return &self.continuous_broken_lengths;
     }
 
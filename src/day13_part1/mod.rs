@@ -0,0 +1,144 @@
+#![allow(clippy::needless_return)]
+use std::collections::HashSet;
+use itertools::Itertools;
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::error::SolverError;
+use crate::grid::Grid;
+
+type Chunk = Grid<char>;
+const ASH: char = '.';
+const ROCK: char = '#';
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //Parse data
+    let all_data = parse_data(input)?;
+    end_parse_phase();
+
+    //Find lines of symmetry and calculate answer
+    let answer = all_data
+        .iter()
+        .enumerate()
+        .map(|(index, chunk)| calculate_chunk_answer(index, chunk))
+        .sum::<Result<usize, SolverError>>()?;
+
+    return Ok(Answer::from(answer));
+}
+
+fn parse_data(input: &str) -> Result<Vec<Chunk>, SolverError> {
+    //Patterns are separated by blank lines
+    let parse_cell = |x: char| (x == ASH || x == ROCK).then_some(x);
+    return input
+        .lines()
+        .enumerate()
+        .collect::<Vec<(usize, &str)>>()
+        .split(|(_, line)| line.is_empty())
+        .filter(|x| !x.is_empty())
+        .map(|x| Grid::parse_lines(x.iter().copied(), parse_cell))
+        .collect::<Result<Vec<Chunk>, SolverError>>();
+}
+
+fn calculate_chunk_answer(chunk_index: usize, chunk: &Chunk) -> Result<usize, SolverError> {
+    const HORIZONTAL_SYMMETRY_ANSWER_COEFFICIENT: usize = 100;
+    let vertical_symmetry_index = find_vertical_line_of_symmetry(chunk_index, chunk)?;
+    let mut horizontal_symmetry_index = None;
+
+    if vertical_symmetry_index.is_none() {
+        horizontal_symmetry_index = find_horizontal_line_of_symmetry(chunk_index, chunk)?;
+    }
+
+    return if let Some(index) = vertical_symmetry_index {
+        Ok(index)
+    } else if let Some(index) = horizontal_symmetry_index {
+        Ok(HORIZONTAL_SYMMETRY_ANSWER_COEFFICIENT * index)
+    } else {
+        Err(SolverError::invalid_input(&format!("Pattern {} has no line of symmetry", chunk_index + 1)))
+    };
+}
+
+fn find_vertical_line_of_symmetry(chunk_index: usize, chunk: &Chunk) -> Result<Option<usize>, SolverError> {
+    let initial_range = (1usize..chunk.get_width())
+        .collect::<Vec<usize>>();
+    let mut possible_indices = HashSet::<usize>::from_iter(initial_range);
+
+    for row in chunk.rows() {
+        find_symmetry_index(row, &mut possible_indices);
+    }
+
+    return extract_answer(chunk_index, &possible_indices, "vertical");
+}
+
+fn find_horizontal_line_of_symmetry(chunk_index: usize, chunk: &Chunk) -> Result<Option<usize>, SolverError> {
+    let initial_range = (1usize..chunk.get_height())
+        .collect::<Vec<usize>>();
+    let mut possible_indices = HashSet::<usize>::from_iter(initial_range);
+
+    for column in chunk.columns() {
+        find_symmetry_index(&column.copied().collect::<Vec<char>>(), &mut possible_indices);
+    }
+
+    return extract_answer(chunk_index, &possible_indices, "horizontal");
+}
+
+fn extract_answer(chunk_index: usize, answer_set: &HashSet<usize>, orientation: &str) -> Result<Option<usize>, SolverError> {
+    return if answer_set.iter().count() == 1 {
+        Ok(Some(*answer_set.iter().take(1).next().unwrap()))
+    } else if answer_set.iter().count() == 0 {
+        Ok(None)
+    } else {
+        Err(SolverError::invalid_input(&format!("Pattern {} has more than one {} line of symmetry", chunk_index + 1, orientation)))
+    };
+}
+
+fn find_symmetry_index(line: &[char], possible_indices: &mut HashSet<usize>) {
+    //Line search init
+    let max_index: isize = (line.len() - 1) as isize;
+    let mut indices_to_remove = Vec::<usize>::new();
+
+    for test_index in possible_indices.iter().sorted() {
+        //Middle-out search init
+        let mut right_cursor = *test_index as isize;
+        let mut left_cursor = (*test_index - 1) as isize;
+        let mut keep_searching = true;
+        let mut is_symmetrical = true;
+        let mut symmetry_length = 0isize;
+
+        //Middle-out contiguous equality
+        while keep_searching {
+            is_symmetrical = line[left_cursor as usize] == line[right_cursor as usize];
+            if is_symmetrical {
+                symmetry_length += 1;
+            }
+
+            left_cursor -= 1;
+            right_cursor += 1;
+            let in_bounds = left_cursor >= 0 && right_cursor <= max_index;
+            keep_searching = is_symmetrical && in_bounds;
+        }
+
+        //Adjust the cursor so that they reflect the symmetric range
+        //(the cursors can cross each other which means no symmetric range)
+        if is_symmetrical {
+            left_cursor += 1;
+            right_cursor -= 1
+        } else{
+            left_cursor += 2;
+            right_cursor -= 2
+        }
+
+        //If no symmetric range, or the symmetric range doesn't extend
+        //to at least one end of the line, or the symmetric range isn't
+        //bigger than the last range, then remove the index. Otherwise,
+        //record the symmetry data and remove any previous symmetry index.
+        if symmetry_length == 0 ||
+           (left_cursor > 0 && right_cursor < max_index)
+        {
+            indices_to_remove.push(*test_index);
+        }
+    }
+
+    //Remove invalid indices
+    for index in indices_to_remove {
+        possible_indices.remove(&index);
+    }
+}
\ No newline at end of file
@@ -1,8 +1,10 @@
+#![allow(clippy::needless_return)]
 use std::collections::HashSet;
 use itertools::Itertools;
-use crate::chunk_solver::Orientation::{Horizontal, Vertical};
+use crate::day13_part2::chunk_solver::Orientation::{Horizontal, Vertical};
+use crate::grid::Grid;
 
-pub type Chunk = Vec<Vec<char>>;
+pub type Chunk = Grid<char>;
 pub const ASH: char = '.';
 pub const ROCK: char = '#';
 
@@ -34,7 +36,7 @@ impl ChunkSolver {
     }
 
     fn find_vertical_line_of_symmetry(&self) -> Option<usize> {
-        let initial_range = (1usize..self.chunk[0].len())
+        let initial_range = (1usize..self.chunk.get_width())
             .collect::<Vec<usize>>();
         let mut possible_indices = HashSet::<usize>::from_iter(initial_range);
         if let Some(remove_data) = &self.remove_option {
@@ -43,15 +45,15 @@ impl ChunkSolver {
             }
         }
 
-        for row_index in 0..self.chunk.len() {
-            self.find_symmetry_index(self.chunk[row_index].clone(), &mut possible_indices);
+        for row in self.chunk.rows() {
+            self.find_symmetry_index(row, &mut possible_indices);
         }
 
         return self.extract_answer(&possible_indices);
     }
 
     fn find_horizontal_line_of_symmetry(&self) -> Option<usize> {
-        let initial_range = (1usize..self.chunk.len())
+        let initial_range = (1usize..self.chunk.get_height())
             .collect::<Vec<usize>>();
         let mut possible_indices = HashSet::<usize>::from_iter(initial_range);
         if let Some(remove_data) = &self.remove_option {
@@ -60,14 +62,8 @@ impl ChunkSolver {
             }
         }
 
-        //Rectangular size so choose first row arbitrarily for max column index
-        for col_index in 0..self.chunk[0].len() {
-            let test_col = self.chunk
-                .iter()
-                .map(|x| x[col_index])
-                .collect::<Vec<char>>();
-
-            self.find_symmetry_index(test_col, &mut possible_indices);
+        for column in self.chunk.columns() {
+            self.find_symmetry_index(&column.copied().collect::<Vec<char>>(), &mut possible_indices);
         }
 
         return self.extract_answer(&possible_indices);
@@ -81,7 +77,7 @@ impl ChunkSolver {
         };
     }
 
-    fn find_symmetry_index(&self, line: Vec<char>, possible_indices: &mut HashSet<usize>) {
+    fn find_symmetry_index(&self, line: &[char], possible_indices: &mut HashSet<usize>) {
         //Line search init
         let max_index: isize = (line.len() - 1) as isize;
         let mut indices_to_remove = Vec::<usize>::new();
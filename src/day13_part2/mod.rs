@@ -0,0 +1,61 @@
+#![allow(clippy::needless_return)]
+
+pub mod chunk_solver;
+use crate::day13_part2::chunk_solver::*;
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::error::SolverError;
+use crate::grid::Grid;
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //Parse data
+    let all_data = parse_data(input)?;
+    end_parse_phase();
+
+    //Find lines of symmetry and calculate answer
+    let answer = all_data
+        .iter()
+        .enumerate()
+        .map(|(index, chunk)| calculate_chunk_answer(index, chunk))
+        .sum::<Result<usize, SolverError>>()?;
+
+    return Ok(Answer::from(answer));
+}
+
+fn parse_data(input: &str) -> Result<Vec<Chunk>, SolverError> {
+    //Patterns are separated by blank lines
+    let parse_cell = |x: char| (x == ASH || x == ROCK).then_some(x);
+    return input
+        .lines()
+        .enumerate()
+        .collect::<Vec<(usize, &str)>>()
+        .split(|(_, line)| line.is_empty())
+        .filter(|x| !x.is_empty())
+        .map(|x| Grid::parse_lines(x.iter().copied(), parse_cell))
+        .collect::<Result<Vec<Chunk>, SolverError>>();
+}
+
+fn calculate_chunk_answer(chunk_index: usize, chunk: &Chunk) -> Result<usize, SolverError> {
+    let original_solver = ChunkSolver { chunk: chunk.clone(), remove_option: None};
+    let Some(original_answer) = original_solver.solve_chunk() else {
+        return Err(SolverError::invalid_input(&format!("Pattern {} doesn't have exactly one line of symmetry", chunk_index + 1)));
+    };
+
+    for position in chunk.positions() {
+        let mut test_chunk = chunk.clone();
+        if test_chunk[position] == ASH {
+            test_chunk[position] = ROCK;
+        } else {
+            test_chunk[position] = ASH;
+        }
+
+        let remove_option = (original_answer.0, original_answer.1);
+        let chunk_solver = ChunkSolver { chunk: test_chunk, remove_option: Some(remove_option) };
+
+        if let Some(possible_answer) = chunk_solver.solve_chunk() {
+            return Ok(possible_answer.2);
+        }
+    }
+
+    return Err(SolverError::invalid_input(&format!("Pattern {} has no smudge that gives a new line of symmetry", chunk_index + 1)));
+}
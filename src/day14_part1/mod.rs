@@ -0,0 +1,52 @@
+#![allow(clippy::needless_return)]
+use itertools::Itertools;
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::error::SolverError;
+use crate::grid::*;
+
+const FIXED: char = '#';
+const MOVABLE: char = 'O';
+const EMPTY: char = '.';
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //Parse data
+    let mut data = parse_data(input)?;
+    end_parse_phase();
+
+    shift_rocks(&mut data);
+
+    //Calculate load
+    let total_rock_load = data
+        .positions()
+        .map(|position| calculate_rock_load(position, &data))
+        .sum::<usize>();
+    return Ok(Answer::from(total_rock_load));
+}
+
+fn parse_data(input: &str) -> Result<Grid<char>, SolverError> {
+    return Grid::parse(input, |x| (x == FIXED || x == MOVABLE || x == EMPTY).then_some(x));
+}
+
+fn shift_rocks(data: &mut Grid<char>) {
+    let mut rock_shifted = true;
+
+    while rock_shifted  {
+        rock_shifted = false;
+
+        for (row_index, col_index) in (1..data.get_height()).cartesian_product(0..data.get_width()) {
+            if data[(row_index, col_index)] == MOVABLE && data[(row_index - 1, col_index)] == EMPTY {
+                data[(row_index, col_index)] = EMPTY;
+                data[(row_index - 1, col_index)] = MOVABLE;
+                rock_shifted = true;
+            }
+        }
+    }
+}
+
+fn calculate_rock_load(position: Position, data: &Grid<char>) -> usize {
+    return match data[position] {
+        MOVABLE => data.get_height() - position.0,
+        _ => 0
+    };
+}
\ No newline at end of file
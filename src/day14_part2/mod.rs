@@ -0,0 +1,96 @@
+#![allow(clippy::needless_return)]
+use std::collections::HashMap;
+use core::ops::Range;
+use itertools::Itertools;
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::direction::Direction;
+use crate::direction::Direction::*;
+use crate::error::SolverError;
+use crate::grid::*;
+
+const FIXED: char = '#';
+const MOVABLE: char = 'O';
+const EMPTY: char = '.';
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //Constants
+    const NUM_CYCLES: usize = 1000000000;
+
+    //Parse data
+    let mut data = parse_data(input)?;
+    end_parse_phase();
+
+    //We can't shift for 1 billion cycles, so we need to find a shortcut. The rock layout
+    //eventually falls into a limit cycle, so record the cycle each layout was first seen on
+    //(using 0-based indexing) and stop at the first repeat. The repeat gives the offset and
+    //length of the limit cycle.
+    let mut history = Vec::<Grid<char>>::new();
+    let mut first_seen = HashMap::<Grid<char>, usize>::new();
+    let (limit_cycle_offset, limit_cycle_length) = loop {
+        shift_rocks(&mut data, North);
+        shift_rocks(&mut data, West);
+        shift_rocks(&mut data, South);
+        shift_rocks(&mut data, East);
+
+        let cycle = history.len();
+        if let Some(previous_cycle) = first_seen.get(&data) {
+            break (*previous_cycle, cycle - previous_cycle);
+        }
+
+        first_seen.insert(data.clone(), cycle);
+        history.push(data.clone());
+    };
+
+    //Predict the final rock layout using the logic outlined above
+    let final_cycle = if NUM_CYCLES - 1 < limit_cycle_offset {
+        NUM_CYCLES - 1
+    } else {
+        limit_cycle_offset + (NUM_CYCLES - 1 - limit_cycle_offset) % limit_cycle_length
+    };
+    let final_data = &history[final_cycle];
+
+    //Calculate load
+    let total_rock_load = final_data
+       .positions()
+       .map(|position| calculate_rock_load(position, final_data))
+       .sum::<usize>();
+    return Ok(Answer::from(total_rock_load));
+}
+
+fn parse_data(input: &str) -> Result<Grid<char>, SolverError> {
+    return Grid::parse(input, |x| (x == FIXED || x == MOVABLE || x == EMPTY).then_some(x));
+}
+
+fn shift_rocks(data: &mut Grid<char>, shift_direction: Direction) {
+    //Skip the edge the rocks are shifting towards
+    let (row_range, col_range): (Range<isize>, Range<isize>) = match shift_direction {
+        North => (1..data.get_height() as isize, 0..data.get_width() as isize),
+        West => (0..data.get_height() as isize, 1..data.get_width() as isize),
+        South => (0..data.get_height() as isize - 1, 0..data.get_width() as isize),
+        East => (0..data.get_height() as isize, 0..data.get_width() as isize - 1)
+    };
+    let (row_shift_offset, col_shift_offset) = shift_direction.get_delta();
+
+    let mut rock_shifted = true;
+    while rock_shifted {
+        rock_shifted = false;
+
+        for (row_index, col_index) in row_range.clone().cartesian_product(col_range.clone()) {
+            let position = (row_index as usize, col_index as usize);
+            let shifted_position = ((row_index + row_shift_offset) as usize, (col_index + col_shift_offset) as usize);
+            if data[position] == MOVABLE && data[shifted_position] == EMPTY {
+                data[position] = EMPTY;
+                data[shifted_position] = MOVABLE;
+                rock_shifted = true;
+            }
+        }
+    }
+}
+
+fn calculate_rock_load(position: Position, data: &Grid<char>) -> usize {
+    return match data[position] {
+       MOVABLE => data.get_height() - position.0,
+       _ => 0
+    };
+}
\ No newline at end of file
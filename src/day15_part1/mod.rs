@@ -0,0 +1,40 @@
+#![allow(clippy::needless_return)]
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::error::SolverError;
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //Parse data
+    let data = parse_data(input)?;
+    end_parse_phase();
+
+    //Calculate answer
+    let hash_sum = data
+        .split(",")
+        .map(calculate_step_hash)
+        .sum::<u64>();
+    return Ok(Answer::from(hash_sum));
+}
+
+//Single line file so casting vector to scalar
+fn parse_data(input: &str) -> Result<String, SolverError> {
+    return input
+        .lines()
+        .map(String::from)
+        .next()
+        .ok_or_else(|| SolverError::invalid_input("Input is empty"));
+}
+
+fn calculate_step_hash(step: &str) -> u64 {
+    const INITIAL_VALUE: u64 = 0;
+    const MULTIPLIER: u64 = 17;
+    const MODULUS: u64 = 256;
+
+    return step
+        .chars()
+        .fold(INITIAL_VALUE, |acc, x| {
+            let mut temp = acc + x as u64;
+            temp *= MULTIPLIER;
+            return temp % MODULUS;
+        });
+}
\ No newline at end of file
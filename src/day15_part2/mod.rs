@@ -0,0 +1,113 @@
+#![allow(clippy::needless_return, clippy::ptr_arg)]
+
+pub mod utility;
+use crate::day15_part2::utility::*;
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::error::*;
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //Constants
+    const NUM_BOXES: usize = 256;
+
+    //Parse data
+    let steps = parse_data(input)?;
+    end_parse_phase();
+
+    //Execute steps
+    let mut lens_boxes = vec![LensBox::new(); NUM_BOXES];
+    execute_steps(&mut lens_boxes, &steps);
+
+    //Calculate combined power
+    let mut combined_power = 0usize;
+    for (box_index, lens_box) in lens_boxes.iter().enumerate()  {
+        for (lens_index, lens) in lens_box.lenses.iter().enumerate() {
+            combined_power += calculate_lens_power(box_index, lens_index, lens.focal_length);
+        }
+    }
+    return Ok(Answer::from(combined_power));
+}
+
+fn parse_data(input: &str) -> Result<Vec<Step>, SolverError> {
+    //Constants
+    const SPLIT_CHAR: char = ',';
+    const INSERTION_SYMBOL: char = '=';
+    const DELETION_SYMBOL: char = '-';
+
+    //Single line file so casting vector to scalar string
+    let Some(raw_data) = input.lines().next() else {
+        return Err(SolverError::invalid_input("Input is empty"));
+    };
+
+    //Convert encoded step data to step objects
+    let mut parsed_data = Vec::<Step>::new();
+    for encoded_step in raw_data.split(SPLIT_CHAR) {
+        let (operation_index, is_insertion) = if let Some(index) = encoded_step.find(INSERTION_SYMBOL) {
+            (index, true)
+        } else if let Some(index) = encoded_step.find(DELETION_SYMBOL) {
+            (index, false)
+        } else {
+            return Err(SolverError::parse_token(0, raw_data, encoded_step, "Step has no '=' or '-' operation"));
+        };
+
+        let lens_label = encoded_step[0..operation_index].to_string();
+        let step = if is_insertion {
+            Step {
+                lens_label: lens_label.clone(),
+                box_index: calculate_label_hash(&lens_label),
+                operation: Operation::Insert,
+                lens_focal_length: Some(parse_token::<u32>(0, raw_data, &encoded_step[operation_index+1..])?),
+            }
+        } else {
+            Step {
+                lens_label: lens_label.clone(),
+                box_index: calculate_label_hash(&lens_label),
+                operation: Operation::Remove,
+                lens_focal_length: None
+            }
+        };
+
+        parsed_data.push(step);
+    }
+
+    return Ok(parsed_data);
+}
+
+fn calculate_label_hash(step: &str) -> usize {
+    const INITIAL_VALUE: usize = 0;
+    const MULTIPLIER: usize = 17;
+    const MODULUS: usize = 256;
+
+    return step
+        .chars()
+        .fold(INITIAL_VALUE, |acc, x| {
+            let mut temp = acc + x as usize;
+            temp *= MULTIPLIER;
+            return temp % MODULUS;
+        });
+}
+
+fn execute_steps(boxes: &mut Vec<LensBox>, steps: &Vec<Step>) {
+    for step in steps {
+        match step.operation {
+            Operation::Insert => {
+                let insertion_lens = Lens { label: step.lens_label.clone(), focal_length: step.lens_focal_length.unwrap() };
+
+                let result = boxes[step.box_index].lenses.replace_first(
+                    |x: &Lens| x.label == step.lens_label,
+                    insertion_lens.clone());
+
+                if result.is_none() {
+                    boxes[step.box_index].lenses.push(insertion_lens);
+                }
+            },
+            Operation::Remove => {
+                boxes[step.box_index].lenses.remove_first(|x: &Lens| x.label == step.lens_label);
+            }
+        };
+    }
+}
+
+fn calculate_lens_power(box_index: usize, lens_index: usize, lens_focal_length: u32) -> usize {
+    return (box_index + 1) * (lens_index + 1) * (lens_focal_length as usize);
+}
\ No newline at end of file
@@ -1,4 +1,5 @@
-#[derive(Debug, Clone)]
+#![allow(clippy::needless_return)]
+#[derive(Debug, Clone, Default)]
 pub struct LensBox {
     pub lenses: Vec<Lens>,
 }
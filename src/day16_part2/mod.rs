@@ -0,0 +1,238 @@
+#![allow(clippy::needless_return, clippy::unnecessary_unwrap)]
+use std::collections::HashSet;
+use itertools::Itertools;
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::direction::Direction;
+use crate::direction::Direction::*;
+use crate::error::SolverError;
+use crate::grid::Grid;
+
+const EMPTY: char = '.';
+const REFLECTOR_45_DEG: char = '/';
+const REFLECTOR_135_DEG: char = '\\';
+const VERTICAL_SPLITTER: char = '|';
+const HORIZONTAL_SPLITTER: char = '-';
+
+struct SolverInputs<'a> {
+    pub starting_pos: (usize, usize),
+    pub beam_direction: Direction,
+    pub feature_map: &'a Grid<char>,
+}
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //Parse data
+    let feature_map = parse_data(input)?;
+    end_parse_phase();
+
+    //Trace beam on each edge tile, keeping track of best position
+    let max_row_index = feature_map.get_height() - 1;
+    let max_col_index = feature_map.get_width() - 1;
+    let mut max_initial_conditions = (usize::MAX, usize::MAX, North);
+    let mut max_count = 0usize;
+
+    for col_index in 0..feature_map.get_width() {
+        let num_energized_tiles = trace_beam_solver((0, col_index), South, &feature_map);
+        update_max(&(0, col_index, South), &num_energized_tiles, &mut max_initial_conditions, &mut max_count);
+    }
+
+    for row_index in 0..feature_map.get_height() {
+        let num_energized_tiles = trace_beam_solver((row_index, max_col_index), West, &feature_map);
+        update_max(&(row_index, max_col_index, West), &num_energized_tiles, &mut max_initial_conditions, &mut max_count);
+    }
+
+    for col_index in 0..feature_map.get_width() {
+        let num_energized_tiles = trace_beam_solver((max_row_index, col_index), North, &feature_map);
+        update_max(&(max_row_index, col_index, North), &num_energized_tiles, &mut max_initial_conditions, &mut max_count);
+    }
+
+    for row_index in 0..feature_map.get_height() {
+        let num_energized_tiles = trace_beam_solver((row_index, 0), East, &feature_map);
+        update_max(&(row_index, 0, East), &num_energized_tiles, &mut max_initial_conditions, &mut max_count);
+    }
+
+    return Ok(Answer::from(max_count));
+}
+
+fn update_max(test_pos: &(usize, usize, Direction), test_count: &usize, max_pos: &mut (usize, usize, Direction), max_count: &mut usize) {
+    if test_count > max_count {
+        *max_pos = *test_pos;
+        *max_count = *test_count;
+    }
+}
+
+fn parse_data(input: &str) -> Result<Grid<char>, SolverError> {
+    return Grid::parse(input, |x| [EMPTY, REFLECTOR_45_DEG, REFLECTOR_135_DEG, VERTICAL_SPLITTER, HORIZONTAL_SPLITTER].contains(&x).then_some(x));
+}
+
+fn trace_beam_solver(starting_pos: (usize, usize), starting_beam_direction: Direction, feature_map: &Grid<char>) -> usize {
+    let mut energized_tiles = HashSet::<(usize, usize, Direction)>::new();
+    let mut work_stack = Vec::<SolverInputs>::new();
+    let initial_inputs = SolverInputs {
+        starting_pos,
+        beam_direction: starting_beam_direction,
+        feature_map,
+    };
+    work_stack.push(initial_inputs);
+
+    while let Some(next_work_item) = work_stack.pop() {
+        let additional_work_items = trace_beam(next_work_item, &mut energized_tiles);
+        for work_item in additional_work_items.into_iter().rev() {
+            work_stack.push(work_item);
+        }
+    }
+
+    return energized_tiles
+        .iter()
+        .map(|x| (x.0, x.1))
+        .unique()
+        .count();
+}
+
+fn trace_beam<'a>(mut solver_inputs: SolverInputs<'a>, energized_tiles: &mut HashSet<(usize, usize, Direction)>) -> Vec<SolverInputs<'a>>
+{
+    let mut complete = false;
+    let mut current_pos = solver_inputs.starting_pos;
+    let mut additional_work_items = Vec::<SolverInputs>::new();
+
+    while !complete {
+        energized_tiles.insert((current_pos.0, current_pos.1, solver_inputs.beam_direction));
+
+        //debug_print(energized_tiles, solver_inputs.feature_map);
+        //print!("\n");
+
+        let feature = solver_inputs.feature_map[current_pos];
+
+        if feature == EMPTY {
+            (current_pos, complete) = handle_empty_case(&current_pos, solver_inputs.beam_direction, solver_inputs.feature_map, energized_tiles);
+        } else if feature == REFLECTOR_45_DEG {
+            (current_pos, solver_inputs.beam_direction, complete) = handle_reflector_45_deg_case(&current_pos, solver_inputs.beam_direction, solver_inputs.feature_map, energized_tiles);
+        } else if feature == REFLECTOR_135_DEG {
+            (current_pos, solver_inputs.beam_direction, complete) = handle_reflector_135_deg_case(&current_pos, solver_inputs.beam_direction, solver_inputs.feature_map, energized_tiles);
+        } else if feature == VERTICAL_SPLITTER {
+            (current_pos, complete, additional_work_items) = handle_vertical_splitter_case(&current_pos, solver_inputs.beam_direction, solver_inputs.feature_map, energized_tiles);
+        } else {
+            (current_pos, complete, additional_work_items) = handle_horizontal_splitter_case(&current_pos, solver_inputs.beam_direction, solver_inputs.feature_map, energized_tiles);
+        }
+    }
+
+    return additional_work_items;
+}
+
+fn handle_empty_case(pos: &(usize, usize), beam_direction: Direction, feature_map: &Grid<char>,
+                     energized_tiles: &HashSet<(usize, usize, Direction)>) -> ((usize, usize), bool)
+{
+    return if let Some(next_pos) = feature_map.step(*pos, beam_direction.get_delta()) {
+        if energized_tiles.contains(&(next_pos.0, next_pos.1, beam_direction)) { (*pos, true) } else { (next_pos, false) }
+    } else {
+        (*pos, true)
+    };
+}
+
+fn handle_reflector_45_deg_case(pos: &(usize, usize), beam_direction: Direction, feature_map: &Grid<char>,
+                                energized_tiles: & HashSet<(usize, usize, Direction)>) -> ((usize, usize), Direction, bool)
+{
+    let new_beam_direction = match beam_direction {
+        North => East,
+        East => North,
+        South => West,
+        West => South
+    };
+
+    return if let Some(next_pos) = feature_map.step(*pos, new_beam_direction.get_delta()) {
+        if energized_tiles.contains(&(next_pos.0, next_pos.1, new_beam_direction)) { (*pos, new_beam_direction, true) } else { (next_pos, new_beam_direction, false) }
+    } else {
+        (*pos, new_beam_direction, true)
+    };
+}
+
+fn handle_reflector_135_deg_case(pos: &(usize, usize), beam_direction: Direction, feature_map: &Grid<char>,
+                                 energized_tiles: &HashSet<(usize, usize, Direction)>) -> ((usize, usize), Direction, bool)
+{
+    let new_beam_direction = match beam_direction {
+        North => West,
+        East => South,
+        South => East,
+        West => North
+    };
+
+    return if let Some(next_pos) = feature_map.step(*pos, new_beam_direction.get_delta()) {
+        if energized_tiles.contains(&(next_pos.0, next_pos.1, new_beam_direction)) { (*pos, new_beam_direction, true) } else { (next_pos, new_beam_direction, false) }
+    } else {
+        (*pos, new_beam_direction, true)
+    };
+}
+
+fn handle_vertical_splitter_case<'a>(pos: &(usize, usize), beam_direction: Direction,
+    feature_map: &'a Grid<char>, energized_tiles: &mut HashSet<(usize, usize, Direction)>) -> ((usize, usize), bool, Vec<SolverInputs<'a>>)
+{
+    let mut additional_work_items = Vec::<SolverInputs>::new();
+
+    return if beam_direction.is_vertical() {
+        let result = handle_empty_case(pos, beam_direction, feature_map, energized_tiles);
+        (result.0, result.1, additional_work_items)
+    } else {
+        let advance_result = feature_map.step(*pos, North.get_delta());
+        if advance_result.is_some() && !energized_tiles.contains(&(advance_result.unwrap().0, advance_result.unwrap().1, North)) {
+            let sub_problem_inputs = SolverInputs {
+                starting_pos: advance_result.unwrap(),
+                beam_direction: North,
+                feature_map,
+            };
+            additional_work_items.push(sub_problem_inputs);
+        }
+
+        let advance_result = feature_map.step(*pos, South.get_delta());
+        if advance_result.is_some() && !energized_tiles.contains(&(advance_result.unwrap().0, advance_result.unwrap().1, South)) {
+            let sub_problem_inputs = SolverInputs {
+                starting_pos: advance_result.unwrap(),
+                beam_direction: South,
+                feature_map,
+            };
+            additional_work_items.push(sub_problem_inputs)
+        }
+
+        (*pos, true, additional_work_items)
+    };
+}
+
+fn handle_horizontal_splitter_case<'a>(pos: &(usize, usize), beam_direction: Direction,
+    feature_map: &'a Grid<char>, energized_tiles: & mut HashSet<(usize, usize, Direction)>) -> ((usize, usize), bool, Vec<SolverInputs<'a>>)
+{
+    let mut additional_work_items = Vec::<SolverInputs>::new();
+
+    return if !beam_direction.is_vertical() {
+        let result = handle_empty_case(pos, beam_direction, feature_map, energized_tiles);
+        (result.0, result.1, additional_work_items)
+    } else {
+        let advance_result = feature_map.step(*pos, West.get_delta());
+        if advance_result.is_some() && !energized_tiles.contains(&(advance_result.unwrap().0, advance_result.unwrap().1, West)) {
+            let sub_problem_inputs = SolverInputs {
+                starting_pos: advance_result.unwrap(),
+                beam_direction: West,
+                feature_map,
+            };
+            additional_work_items.push(sub_problem_inputs);
+        }
+
+        let advance_result = feature_map.step(*pos, East.get_delta());
+        if advance_result.is_some() && !energized_tiles.contains(&(advance_result.unwrap().0, advance_result.unwrap().1, East)) {
+            let sub_problem_inputs = SolverInputs {
+                starting_pos: advance_result.unwrap(),
+                beam_direction: East,
+                feature_map,
+            };
+            additional_work_items.push(sub_problem_inputs);
+        }
+
+        (*pos, true, additional_work_items)
+    };
+}
+
+#[allow(dead_code)]
+fn debug_print(energized_tiles: &HashSet<(usize, usize, Direction)>, feature_map: &Grid<char>) {
+    let energized_map = feature_map.map_with_position(|position, _| Direction::ALL
+        .iter()
+        .any(|&x| energized_tiles.contains(&(position.0, position.1, x))));
+    print!("{}", energized_map.render(|&x| if x {'#'} else {'.'}));
+}
@@ -0,0 +1,84 @@
+#![allow(clippy::needless_return)]
+use std::env;
+use std::fs;
+use sandbox::day17::*;
+use sandbox::day17::route::*;
+use sandbox::direction::Direction;
+use sandbox::registry::find_solver;
+use sandbox::runner::*;
+
+const USAGE: &str = "\
+Usage: day17 [input path | -] [options]
+
+Options:
+    --crucible normal|ultra     Start from the part 1 or part 2 rules (default normal)
+    --min-straight <n>          Blocks to move in a straight line before turning or stopping
+    --max-straight <n>          Blocks the crucible can move in a straight line before it has to turn
+    --allow-reverse             Let the crucible turn around on the spot
+    --start <letters>           Start directions as U/R/D/L letters, e.g. RD
+    --ascii                     Print the optimal route over the map
+    --svg <path>                Write the map and the optimal route as an SVG heat map
+
+Input is read the same way as for day17_part1.";
+
+//Runs day 17 with any set of crucible rules
+fn main() {
+    let mut args = env::args().skip(1).collect::<Vec<String>>();
+    let rules = take_rules(&mut args);
+    let print_ascii = take_flag(&mut args, "--ascii");
+    let svg_path = take_option(&mut args, "--svg", USAGE);
+
+    let solver = find_solver(17, 1).unwrap();
+    let input = read_positional_input(solver, &args).unwrap_or_else(|x| exit_with_usage(&x, USAGE));
+
+    let heat_loss_reference = parse_data(&input).unwrap_or_else(|x| exit_with_error(17, &x.to_string()));
+    let min_route = find_minimum_heat_loss(&heat_loss_reference, &rules).unwrap_or_else(|x| exit_with_error(17, &x.to_string()));
+
+    if print_ascii {
+        print!("{}", render_ascii(&heat_loss_reference, min_route.get_path()));
+    }
+    if let Some(path) = svg_path {
+        if let Err(error) = fs::write(&path, render_svg(&heat_loss_reference, min_route.get_path())) {
+            exit_with_error(17, &format!("Could not write {}: {}", path, error));
+        }
+    }
+    println!("The minimum heat loss is {}", min_route.get_cost());
+}
+
+//Builds the rules from the --crucible preset and any options overriding parts of it
+fn take_rules(args: &mut Vec<String>) -> CrucibleRules {
+    let mut rules = match take_option(args, "--crucible", USAGE).as_deref() {
+        None | Some("normal") => CrucibleRules::normal(),
+        Some("ultra") => CrucibleRules::ultra(),
+        Some(other) => exit_with_usage(&format!("Unknown crucible '{}'", other), USAGE)
+    };
+
+    if let Some(min_straight) = take_option(args, "--min-straight", USAGE) {
+        rules.min_straight = parse_number(&min_straight, "minimum straight distance");
+    }
+    if let Some(max_straight) = take_option(args, "--max-straight", USAGE) {
+        rules.max_straight = parse_number(&max_straight, "maximum straight distance");
+    }
+    if take_flag(args, "--allow-reverse") {
+        rules.allow_reverse = true;
+    }
+    if let Some(start) = take_option(args, "--start", USAGE) {
+        rules.start_directions = start
+            .chars()
+            .map(|x| Direction::from_letter(x).unwrap_or_else(|| exit_with_usage(&format!("Invalid start direction '{}'", x), USAGE)))
+            .collect();
+    }
+
+    if let Err(error) = rules.validate() {
+        exit_with_usage(&error.to_string(), USAGE);
+    }
+
+    return rules;
+}
+
+fn parse_number(text: &str, name: &str) -> u32 {
+    return match text.parse::<u32>() {
+        Ok(x) => x,
+        Err(_) => exit_with_usage(&format!("Invalid {} '{}'", name, text), USAGE)
+    };
+}
@@ -0,0 +1,145 @@
+#![allow(clippy::needless_return)]
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::direction::Direction;
+use crate::direction::Direction::*;
+use crate::error::SolverError;
+use crate::grid::Grid;
+use crate::pathfinding::{astar_from_any, SearchResult};
+
+pub mod route;
+
+//How a crucible may move through the city
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrucibleRules {
+    //Blocks the crucible has to move in a straight line before it can turn or stop at the goal
+    pub min_straight: u32,
+    //Blocks the crucible can move in a straight line before it has to turn
+    pub max_straight: u32,
+    //Whether the crucible can turn around on the spot, subject to the same limits as turning left or right
+    pub allow_reverse: bool,
+    //Directions the crucible can start out in from the top left corner
+    pub start_directions: Vec<Direction>,
+}
+
+impl CrucibleRules {
+    //Part 1 rules
+    pub fn normal() -> Self {
+        return CrucibleRules {
+            min_straight: 1,
+            max_straight: 3,
+            allow_reverse: false,
+            start_directions: vec![East, South],
+        };
+    }
+
+    //Part 2 rules
+    pub fn ultra() -> Self {
+        return CrucibleRules {
+            min_straight: 4,
+            max_straight: 10,
+            allow_reverse: false,
+            start_directions: vec![East, South],
+        };
+    }
+
+    pub fn validate(&self) -> Result<(), SolverError> {
+        if self.max_straight == 0 {
+            return Err(SolverError::invalid_input("The crucible has to be able to move at least one block in a straight line"));
+        }
+        if self.min_straight > self.max_straight {
+            return Err(SolverError::invalid_input("The minimum straight distance is more than the maximum"));
+        }
+        if self.start_directions.is_empty() {
+            return Err(SolverError::invalid_input("The crucible needs at least one start direction"));
+        }
+
+        return Ok(());
+    }
+}
+
+//State on entering a block. straight_count is the number of blocks moved in direction so far, which is 0
+//only for the starting states.
+#[derive(Eq, PartialEq, Hash, Clone, Debug)]
+pub struct NodeState {
+    pub row_index: usize,
+    pub col_index: usize,
+    pub direction: Direction,
+    pub straight_count: u32,
+}
+
+pub fn solve_with_rules(input: &str, rules: &CrucibleRules) -> Result<Answer, SolverError> {
+    //Parse data
+    let heat_loss_reference = parse_data(input)?;
+    end_parse_phase();
+
+    //Find minimum
+    return find_minimum_heat_loss(&heat_loss_reference, rules)
+        .map(|x| Answer::from(x.get_cost()));
+}
+
+pub fn parse_data(input: &str) -> Result<Grid<usize>, SolverError> {
+    return Grid::parse(input, |x| x.to_digit(10).map(|x| x as usize));
+}
+
+fn get_adjacent_nodes(current_state: &NodeState, heat_loss_reference: &Grid<usize>, rules: &CrucibleRules) -> Vec<NodeState> {
+    let mut next_directions = Vec::<Direction>::new();
+    if current_state.straight_count < rules.max_straight {
+        next_directions.push(current_state.direction);
+    }
+    if current_state.straight_count >= rules.min_straight {
+        next_directions.push(current_state.direction.turn_left());
+        next_directions.push(current_state.direction.turn_right());
+        if rules.allow_reverse {
+            next_directions.push(current_state.direction.reverse());
+        }
+    }
+
+    return next_directions
+        .into_iter()
+        .filter_map(|direction| {
+            let current_pos = (current_state.row_index, current_state.col_index);
+            let next_pos = heat_loss_reference.step(current_pos, direction.get_delta())?;
+            let straight_count = if direction == current_state.direction { current_state.straight_count + 1 } else { 1 };
+            Some(NodeState {
+                row_index: next_pos.0,
+                col_index: next_pos.1,
+                direction,
+                straight_count,
+            })
+        })
+        .collect();
+}
+
+//A* search from the top left corner to any state in the bottom right corner, so every start direction, end
+//direction, and straight count is covered by the one search. The path holds the state on entering each
+//block of the route.
+pub fn find_minimum_heat_loss(heat_loss_reference: &Grid<usize>, rules: &CrucibleRules) -> Result<SearchResult<NodeState, usize>, SolverError> {
+    rules.validate()?;
+
+    let goal = (heat_loss_reference.get_height() - 1, heat_loss_reference.get_width() - 1);
+    let initial_states = rules.start_directions
+        .iter()
+        .map(|&direction| NodeState {
+            row_index: 0,
+            col_index: 0,
+            direction,
+            straight_count: 0,
+        });
+
+    //Every remaining block costs at least the cheapest block on the map, so the estimate never overshoots
+    let min_block_heat_loss = heat_loss_reference.iter().map(|x| *x.1).min().unwrap_or(0);
+
+    return astar_from_any(
+        initial_states,
+        |state| get_adjacent_nodes(state, heat_loss_reference, rules)
+            .into_iter()
+            .map(|x| {
+                let movement_cost = heat_loss_reference[(x.row_index, x.col_index)];
+                (x, movement_cost)
+            }),
+        |state| min_block_heat_loss * (goal.0 - state.row_index + goal.1 - state.col_index),
+        //Only the start states have a straight count of 0, and they already satisfy the goal on a single block map
+        |state| (state.row_index, state.col_index) == goal && (state.straight_count == 0 || state.straight_count >= rules.min_straight)
+    ).ok_or_else(|| SolverError::invalid_input("There is no path to the bottom right corner"));
+}
@@ -0,0 +1,62 @@
+#![allow(clippy::needless_return)]
+use crate::day17::NodeState;
+use crate::direction::Direction::*;
+use crate::grid::Grid;
+
+const SVG_CELL_SIZE: usize = 10;
+//Light yellow for the cheapest blocks through to dark red for the most expensive ones
+const SVG_COLD_COLOR: (f64, f64, f64) = (255.0, 237.0, 160.0);
+const SVG_HOT_COLOR: (f64, f64, f64) = (189.0, 0.0, 38.0);
+
+//Draws the route over the heat loss map, marking each block the crucible enters with the direction it moved in.
+//The starting block keeps its digit since its heat loss isn't counted.
+pub fn render_ascii(heat_loss_reference: &Grid<usize>, route: &[NodeState]) -> String {
+    let mut path_map = heat_loss_reference.map(|x| char::from_digit(*x as u32, 10).unwrap_or('?'));
+    for node in route.iter().skip(1) {
+        path_map[(node.row_index, node.col_index)] = match node.direction {
+            North => '^',
+            East => '>',
+            South => 'v',
+            West => '<'
+        };
+    }
+
+    return path_map.to_string();
+}
+
+//Heat map of the blocks with the route drawn as a line through the centers of the blocks it visits
+pub fn render_svg(heat_loss_reference: &Grid<usize>, route: &[NodeState]) -> String {
+    let width = heat_loss_reference.get_width() * SVG_CELL_SIZE;
+    let height = heat_loss_reference.get_height() * SVG_CELL_SIZE;
+    let min_heat_loss = heat_loss_reference.iter().map(|x| *x.1).min().unwrap_or(0);
+    let max_heat_loss = heat_loss_reference.iter().map(|x| *x.1).max().unwrap_or(0);
+
+    let mut lines = Vec::<String>::new();
+    lines.push(format!(r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}">"#, width, height));
+    for ((row_index, col_index), heat_loss) in heat_loss_reference.iter() {
+        let (red, green, blue) = get_heat_color(*heat_loss, min_heat_loss, max_heat_loss);
+        lines.push(format!(r#"<rect x="{}" y="{}" width="{size}" height="{size}" fill="rgb({},{},{})"><title>{}</title></rect>"#,
+            col_index * SVG_CELL_SIZE, row_index * SVG_CELL_SIZE, red, green, blue, heat_loss, size = SVG_CELL_SIZE));
+    }
+
+    let points = route
+        .iter()
+        .map(|x| format!("{},{}", x.col_index * SVG_CELL_SIZE + SVG_CELL_SIZE / 2, x.row_index * SVG_CELL_SIZE + SVG_CELL_SIZE / 2))
+        .collect::<Vec<String>>()
+        .join(" ");
+    lines.push(format!(r#"<polyline points="{}" fill="none" stroke="black" stroke-width="2" stroke-linejoin="round"/>"#, points));
+    lines.push(String::from("</svg>"));
+
+    return lines.join("\n") + "\n";
+}
+
+fn get_heat_color(heat_loss: usize, min_heat_loss: usize, max_heat_loss: usize) -> (u8, u8, u8) {
+    let fraction = if max_heat_loss > min_heat_loss {
+        (heat_loss - min_heat_loss) as f64 / (max_heat_loss - min_heat_loss) as f64
+    } else {
+        0.0
+    };
+    let blend = |cold: f64, hot: f64| (cold + (hot - cold) * fraction).round() as u8;
+
+    return (blend(SVG_COLD_COLOR.0, SVG_HOT_COLOR.0), blend(SVG_COLD_COLOR.1, SVG_HOT_COLOR.1), blend(SVG_COLD_COLOR.2, SVG_HOT_COLOR.2));
+}
@@ -1,24 +1,13 @@
+#![allow(clippy::needless_return)]
 use std::ops;
-use crate::grid_data::PerimeterMovement::*;
+use crate::direction::Direction;
+use crate::direction::Direction::*;
 
 pub const SQUARE_EDGE_LENGTH: f32 = 1f32;
 pub type DiscreteCoordinateInt = (i32, i32);
 
-#[derive(Clone, Copy, PartialEq, Eq)]
-pub enum PerimeterMovement {
-    North,
-    NorthToEast,
-    East,
-    EastToSouth,
-    South,
-    SouthToWest,
-    West,
-    WestToNorth,
-    SouthToEast,
-    EastToNorth,
-    NorthToWest,
-    WestToSouth,
-}
+//Direction of the step into a square followed by the direction of the step out of it
+pub type PerimeterMovement = (Direction, Direction);
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum Orientation {
@@ -71,55 +60,18 @@ impl ops::Add<DiscreteCoordinateFloat> for &DiscreteCoordinateFloat {
 }
 
 pub fn get_perimeter_movement(n_minus_one: &DiscreteCoordinateFloat, n: &DiscreteCoordinateFloat, n_plus_one: &DiscreteCoordinateFloat) -> PerimeterMovement {
-    if n.x_eq(n_minus_one) && n.x_eq(n_plus_one) && n_plus_one.point.1 > n.point.1 {
-        return North;
-    }
-
-    if n.x_eq(n_minus_one) && n.point.1 > n_minus_one.point.1 && n_plus_one.point.0 > n.point.0 {
-        return NorthToEast;
-    }
-
-    if n.y_eq(n_minus_one) && n.y_eq(n_plus_one) && n_plus_one.point.0 > n.point.0 {
-        return East
-    }
-
-    if n.y_eq(n_minus_one) && n.point.0 > n_minus_one.point.0 && n_plus_one.point.1 < n.point.1 {
-        return EastToSouth;
-    }
-
-    if n.x_eq(n_minus_one) && n.x_eq(n_plus_one) && n_plus_one.point.1 < n.point.1 {
-        return South;
-    }
-
-    if n.x_eq(n_minus_one) && n.point.1 < n_minus_one.point.1 && n_plus_one.point.0 < n.point.0 {
-        return SouthToWest;
-    }
-
-    if n.y_eq(n_minus_one) && n.y_eq(n_plus_one) && n_plus_one.point.0 < n.point.0 {
-        return West
-    }
-
-    if n.y_eq(n_minus_one) && n.point.0 < n_minus_one.point.0 && n_plus_one.point.1 > n.point.1 {
-        return WestToNorth;
-    }
-
-    if n.x_eq(n_minus_one) && n.point.1 < n_minus_one.point.1 && n_plus_one.point.0 > n.point.0 {
-        return SouthToEast;
-    }
-
-    if n.y_eq(n_minus_one) && n.point.0 > n_minus_one.point.0 && n_plus_one.point.1 > n.point.1 {
-        return EastToNorth;
-    }
-
-    if n.x_eq(n_minus_one) && n.point.1 > n_minus_one.point.1 && n_plus_one.point.0 < n.point.0 {
-        return NorthToWest;
-    }
-
-    if n.y_eq(n_minus_one) && n.point.0 < n_minus_one.point.0 && n_plus_one.point.1 < n.point.1 {
-        return WestToSouth;
-    }
+    return (get_step_direction(n_minus_one, n), get_step_direction(n, n_plus_one));
+}
 
-    panic!("Condition not mapped to perimeter movement");
+//Squares are adjacent, with y pointing north
+fn get_step_direction(from: &DiscreteCoordinateFloat, to: &DiscreteCoordinateFloat) -> Direction {
+    return if to.x_eq(from) {
+        if to.point.1 > from.point.1 { North } else { South }
+    } else if to.point.0 > from.point.0 {
+        East
+    } else {
+        West
+    };
 }
 
 fn float_equals(lhs: f32, rhs: f32) -> bool {
@@ -0,0 +1,123 @@
+#![allow(clippy::needless_return)]
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::direction::Direction;
+use crate::direction::Direction::*;
+use crate::error::SolverError;
+
+type DigInfo = (Direction, isize);
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //Parse data
+    let dig_data = parse_data(input)?;
+    end_parse_phase();
+
+    //The trenches here run for millions of blocks, so unlike part 1 the area comes from the corners alone
+    let area = calculate_lagoon_area(&dig_data);
+    return Ok(Answer::from(area));
+}
+
+fn parse_data(input: &str) -> Result<Vec<DigInfo>, SolverError> {
+    let dig_data = input
+        .lines()
+        .enumerate()
+        .map(|(line_index, line)| {
+            let Some(hex_data) = line.split(" ").nth(2) else {
+                return Err(SolverError::parse_line(line_index, line, "Expected a direction, distance, and color"));
+            };
+            return hex_data_to_dig_info(hex_data)
+                .ok_or_else(|| SolverError::parse_token(line_index, line, hex_data, "Invalid hex instruction"));
+        })
+        .collect::<Result<Vec<DigInfo>, SolverError>>()?;
+
+    validate_dig_plan(input, &dig_data)?;
+    return Ok(dig_data);
+}
+
+//Hex data has the form (#ddddde) where d is the hex distance and e the direction code
+fn hex_data_to_dig_info(hex_data: &str) -> Option<DigInfo> {
+    const LENGTH_STARTING_INDEX: usize = 2;
+    const LENGTH_NUM_HEX_DIGITS: usize = 5;
+
+    if !hex_data.is_ascii() || !hex_data.starts_with("(#") || !hex_data.ends_with(")") ||
+        hex_data.len() != LENGTH_STARTING_INDEX + LENGTH_NUM_HEX_DIGITS + 2 {
+        return None;
+    }
+
+    let hex_distance_string = &hex_data[LENGTH_STARTING_INDEX..LENGTH_STARTING_INDEX + LENGTH_NUM_HEX_DIGITS];
+    let decimal_distance = isize::from_str_radix(hex_distance_string, 16).ok()?;
+    if decimal_distance == 0 {
+        return None;
+    }
+    let direction = match hex_data.chars().nth(LENGTH_STARTING_INDEX + LENGTH_NUM_HEX_DIGITS).unwrap() {
+        '0' => East,
+        '1' => South,
+        '2' => West,
+        '3' => North,
+        _ => return None
+    };
+
+    return Some((direction, decimal_distance));
+}
+
+//The perimeter walk needs a non-empty plan that ends where it started, where no step doubles back on the one before
+//and the trench only touches itself where consecutive steps meet. A last step that doubles back on the first always
+//runs into another one. dig_data holds one step per line of the input.
+fn validate_dig_plan(input: &str, dig_data: &Vec<DigInfo>) -> Result<(), SolverError> {
+    if dig_data.is_empty() {
+        return Err(SolverError::invalid_input("Input is empty"));
+    }
+
+    //Each step's trench as the (row, column) corners of its bounding box
+    let mut trenches = Vec::<((isize, isize), (isize, isize))>::new();
+    let mut position = (0isize, 0isize);
+    for dig_info in dig_data {
+        let delta = dig_info.0.get_delta();
+        let next_position = (position.0 + delta.0 * dig_info.1, position.1 + delta.1 * dig_info.1);
+        trenches.push(((position.0.min(next_position.0), position.1.min(next_position.1)),
+            (position.0.max(next_position.0), position.1.max(next_position.1))));
+        position = next_position;
+    }
+
+    if position != (0, 0) {
+        return Err(SolverError::invalid_input("The dig plan does not return to its starting point"));
+    }
+
+    let last_index = dig_data.len() - 1;
+    for (line_index, line) in input.lines().enumerate().skip(1) {
+        if dig_data[line_index].0 == dig_data[line_index - 1].0.reverse() {
+            return Err(SolverError::parse_line(line_index, line, "The step doubles back on the one before"));
+        }
+
+        //Axis aligned trenches touch exactly when their bounding boxes overlap. The last step also meets the first.
+        let (min, max) = trenches[line_index];
+        let crossed_index = (0..line_index - 1)
+            .filter(|x| !(*x == 0 && line_index == last_index))
+            .find(|x| trenches[*x].0.0 <= max.0 && min.0 <= trenches[*x].1.0 && trenches[*x].0.1 <= max.1 && min.1 <= trenches[*x].1.1);
+        if let Some(crossed_index) = crossed_index {
+            return Err(SolverError::parse_line(line_index, line, &format!("The trench runs into the one dug on line {}", crossed_index + 1)));
+        }
+    }
+
+    return Ok(());
+}
+
+//The shoelace theorem over the corners gives the area inside the line through the middle of the trench. The
+//blocks along that line are the polygon's boundary lattice points, so Pick's theorem gives the blocks inside
+//it, and the trench's own blocks are added back on.
+fn calculate_lagoon_area(dig_data: &Vec<DigInfo>) -> i64 {
+    let mut position = (0i64, 0i64);
+    let mut twice_area = 0i64;
+    let mut perimeter = 0i64;
+
+    for dig_info in dig_data {
+        let delta = dig_info.0.get_delta();
+        let next_position = (position.0 + delta.0 as i64 * dig_info.1 as i64, position.1 + delta.1 as i64 * dig_info.1 as i64);
+        twice_area += position.0 * next_position.1 - next_position.0 * position.1;
+        perimeter += dig_info.1 as i64;
+        position = next_position;
+    }
+
+    let interior = (twice_area.abs() - perimeter) / 2 + 1;
+    return interior + perimeter;
+}
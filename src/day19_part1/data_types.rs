@@ -1,3 +1,4 @@
+#![allow(clippy::needless_return)]
 use RuleResult::*;
 use RelationalType::*;
 use PartType::*;
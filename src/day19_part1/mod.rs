@@ -0,0 +1,183 @@
+#![allow(clippy::needless_return, clippy::ptr_arg)]
+use std::collections::HashMap;
+use itertools::Itertools;
+use regex::Regex;
+
+use crate::day19_part1::data_types::{Rule, Workflow, Part, RuleResult};
+use crate::day19_part1::data_types::PartType::{Aerodynamic, ExtremelyCool, Musical, Shiny};
+use crate::day19_part1::data_types::RelationalType::{GreaterThan, LessThan};
+use crate::day19_part1::data_types::RuleResult::{Accept, GoToWorkflow, NextRule, Reject};
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::error::*;
+
+pub mod data_types;
+
+const STARTING_WORKFLOW_NAME: &str = "in";
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    let (workflows, parts) = parse_data(input)?;
+    end_parse_phase();
+    let ratings_sum = parts
+        .iter()
+        .filter(|&part| part_accepted(part, &workflows))
+        .map(|part| part.get_ratings_sum())
+        .sum::<u64>();
+    return Ok(Answer::from(ratings_sum));
+}
+
+fn parse_data(input: &str) -> Result<(HashMap<String, Workflow>, Vec<Part>), SolverError> {
+    let mut workflows = HashMap::<String, Workflow>::new();
+    let mut parts = Vec::<Part>::new();
+    let mut in_workflow_definitions = true;
+
+    for (line_index, line) in input.lines().map(String::from).enumerate() {
+        if !line.is_empty() {
+            if in_workflow_definitions {
+                let workflow = parse_workflow_definition(line_index, &line)?;
+                workflows.insert(workflow.name.clone(), workflow);
+            } else {
+                parts.push(parse_part_definition(line_index, &line)?);
+            }
+        } else {
+            in_workflow_definitions = false;
+        }
+    }
+
+    validate_workflows(&workflows)?;
+    return Ok((workflows, parts));
+}
+
+fn parse_workflow_definition(line_index: usize, line: &String) -> Result<Workflow, SolverError> {
+    let re = Regex::new(r"^([a-z]+)\{(.+)\}$").unwrap();
+    let Some(captures) = re.captures(line) else {
+        return Err(SolverError::parse_line(line_index, line, "Expected a workflow in the form name{rules}"));
+    };
+
+    let mut rules = Vec::<Rule>::new();
+    for rule_definition in captures.get(2).unwrap().as_str().split(",") {
+        rules.push(parse_rule_definition(line_index, line, rule_definition)?);
+    }
+
+    //The last rule has to apply unconditionally, otherwise a part could run off the end of the workflow
+    if rules.last().unwrap().variant.is_some() {
+        return Err(SolverError::parse_line(line_index, line, "Workflow must end with an unconditional rule"));
+    }
+
+    return Ok(Workflow {
+        name: captures.get(1).unwrap().as_str().to_string(),
+        rules
+    });
+}
+
+fn parse_rule_definition(line_index: usize, line: &str, rule_definition: &str) -> Result<Rule, SolverError> {
+    let rule_split_result = rule_definition.split(":").collect_vec();
+
+    let rule = match rule_split_result.len() {
+        2 => {
+            let condition = rule_split_result[0];
+            let variant = match condition.chars().next() {
+                Some('x') => Some(ExtremelyCool),
+                Some('m') => Some(Musical),
+                Some('a') => Some(Aerodynamic),
+                Some('s') => Some(Shiny),
+                _ => return Err(SolverError::parse_token(line_index, line, condition, "Invalid variant code"))
+            };
+
+            let operator = match condition.chars().nth(1) {
+                Some('<') => Some(LessThan),
+                Some('>') => Some(GreaterThan),
+                _ => return Err(SolverError::parse_token(line_index, line, condition, "Invalid comparison operator"))
+            };
+
+            let rule_threshold = Some(parse_token::<u64>(line_index, line, &condition[2..])?);
+            let true_result_code = rule_split_result[1].to_string();
+
+            Rule {
+                variant,
+                operator,
+                rule_threshold,
+                true_result: true_result_code_to_value(&true_result_code)
+            }
+        },
+        1 => {
+            Rule {
+                variant: None,
+                operator: None,
+                rule_threshold: None,
+                true_result: true_result_code_to_value(rule_definition),
+            }
+        },
+        _ => return Err(SolverError::parse_token(line_index, line, rule_definition, "Rule has more than one ':'"))
+    };
+
+    return Ok(rule);
+}
+
+//Every workflow a rule jumps to has to exist, as does the starting workflow
+fn validate_workflows(workflows: &HashMap<String, Workflow>) -> Result<(), SolverError> {
+    if !workflows.contains_key(STARTING_WORKFLOW_NAME) {
+        return Err(SolverError::invalid_input("There is no starting workflow named 'in'"));
+    }
+
+    for workflow in workflows.values() {
+        for rule in &workflow.rules {
+            if let GoToWorkflow(name) = &rule.true_result {
+                if !workflows.contains_key(name) {
+                    return Err(SolverError::invalid_input(&format!("Workflow {} jumps to unknown workflow {}", workflow.name, name)));
+                }
+            }
+        }
+    }
+
+    return Ok(());
+}
+
+fn true_result_code_to_value(value: &str) -> RuleResult {
+    return if value == "A" {
+        Accept
+    } else if value == "R" {
+        Reject
+    } else {
+        GoToWorkflow(value.to_string())
+    };
+}
+
+fn parse_part_definition(line_index: usize, part_definition: &String) -> Result<Part, SolverError> {
+    let re = Regex::new(r"^\{x=(\d+),m=(\d+),a=(\d+),s=(\d+)\}$").unwrap();
+    let Some(captures) = re.captures(part_definition) else {
+        return Err(SolverError::parse_line(line_index, part_definition, "Expected a part in the form {x=..,m=..,a=..,s=..}"));
+    };
+    let parse_rating = |index: usize| parse_token::<u64>(line_index, part_definition, captures.get(index).unwrap().as_str());
+
+    return Ok(Part {
+        x: parse_rating(1)?,
+        m: parse_rating(2)?,
+        a: parse_rating(3)?,
+        s: parse_rating(4)?,
+    });
+}
+
+fn part_accepted(part: &Part, workflows: &HashMap<String, Workflow>) -> bool {
+    let mut status = None;
+    let mut workflow = &workflows[STARTING_WORKFLOW_NAME];
+    let mut rule_index = 0usize;
+
+    while status.is_none() || (status != Some(Accept) && status != Some(Reject)) {
+        let rule = &workflow.rules[rule_index];
+        status = Some(rule.evaluate(part));
+
+        match &status {
+            Some(NextRule) => {
+                rule_index += 1;
+            },
+            Some(GoToWorkflow(workflow_name)) => {
+                workflow = &workflows[workflow_name];
+                rule_index = 0usize;
+            },
+            _ => {}
+        }
+    }
+
+    return status.unwrap() == Accept;
+}
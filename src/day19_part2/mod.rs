@@ -0,0 +1,181 @@
+#![allow(clippy::needless_return, clippy::ptr_arg)]
+use std::collections::{HashMap, VecDeque};
+use std::mem::discriminant;
+use itertools::Itertools;
+use regex::Regex;
+
+use crate::day19_part2::data_types::{Rule, Workflow, RuleResult, AggregatePart};
+use crate::day19_part2::data_types::PartType::{Aerodynamic, ExtremelyCool, Musical, Shiny};
+use crate::day19_part2::data_types::RelationalType::{GreaterThan, LessThan};
+use crate::day19_part2::data_types::RuleResult::{Accept, GoToWorkflow, NextRule, Reject};
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::error::*;
+
+pub mod data_types;
+
+const STARTING_WORKFLOW_NAME: &str = "in";
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    let workflows = parse_data(input)?;
+    end_parse_phase();
+    let accepted_parts = get_all_accepted_parts(&workflows);
+
+
+    let total_sum = accepted_parts.iter().map(|x| x.get_parts_combinations()).sum::<u64>();
+
+    return Ok(Answer::from(total_sum));
+}
+
+fn parse_data(input: &str) -> Result<HashMap<String, Workflow>, SolverError> {
+    let mut workflows = HashMap::<String, Workflow>::new();
+    let lines = input.lines().map(String::from).collect_vec();
+    let mut line_index = 0usize;
+
+    while line_index < lines.len() && !lines[line_index].is_empty() {
+        let workflow = parse_workflow_definition(line_index, &lines[line_index])?;
+        workflows.insert(workflow.name.clone(), workflow);
+        line_index += 1
+    }
+
+    validate_workflows(&workflows)?;
+    return Ok(workflows);
+}
+
+fn parse_workflow_definition(line_index: usize, line: &String) -> Result<Workflow, SolverError> {
+    let re = Regex::new(r"^([a-z]+)\{(.+)\}$").unwrap();
+    let Some(captures) = re.captures(line) else {
+        return Err(SolverError::parse_line(line_index, line, "Expected a workflow in the form name{rules}"));
+    };
+
+    let mut rules = Vec::<Rule>::new();
+    for rule_definition in captures.get(2).unwrap().as_str().split(",") {
+        rules.push(parse_rule_definition(line_index, line, rule_definition)?);
+    }
+
+    //The last rule has to apply unconditionally, otherwise a part could run off the end of the workflow
+    if rules.last().unwrap().variant.is_some() {
+        return Err(SolverError::parse_line(line_index, line, "Workflow must end with an unconditional rule"));
+    }
+
+    return Ok(Workflow {
+        name: captures.get(1).unwrap().as_str().to_string(),
+        rules
+    });
+}
+
+fn parse_rule_definition(line_index: usize, line: &str, rule_definition: &str) -> Result<Rule, SolverError> {
+    let rule_split_result = rule_definition.split(":").collect_vec();
+
+    let rule = match rule_split_result.len() {
+        2 => {
+            let condition = rule_split_result[0];
+            let variant = match condition.chars().next() {
+                Some('x') => Some(ExtremelyCool),
+                Some('m') => Some(Musical),
+                Some('a') => Some(Aerodynamic),
+                Some('s') => Some(Shiny),
+                _ => return Err(SolverError::parse_token(line_index, line, condition, "Invalid variant code"))
+            };
+
+            let operator = match condition.chars().nth(1) {
+                Some('<') => Some(LessThan),
+                Some('>') => Some(GreaterThan),
+                _ => return Err(SolverError::parse_token(line_index, line, condition, "Invalid comparison operator"))
+            };
+
+            let rule_threshold = Some(parse_token::<u64>(line_index, line, &condition[2..])?);
+            let true_result_code = rule_split_result[1].to_string();
+
+            Rule {
+                variant,
+                operator,
+                rule_threshold,
+                true_result: true_result_code_to_value(&true_result_code)
+            }
+        },
+        1 => {
+            Rule {
+                variant: None,
+                operator: None,
+                rule_threshold: None,
+                true_result: true_result_code_to_value(rule_definition),
+            }
+        },
+        _ => return Err(SolverError::parse_token(line_index, line, rule_definition, "Rule has more than one ':'"))
+    };
+
+    return Ok(rule);
+}
+
+//Every workflow a rule jumps to has to exist, as does the starting workflow
+fn validate_workflows(workflows: &HashMap<String, Workflow>) -> Result<(), SolverError> {
+    if !workflows.contains_key(STARTING_WORKFLOW_NAME) {
+        return Err(SolverError::invalid_input("There is no starting workflow named 'in'"));
+    }
+
+    for workflow in workflows.values() {
+        for rule in &workflow.rules {
+            if let GoToWorkflow(name) = &rule.true_result {
+                if !workflows.contains_key(name) {
+                    return Err(SolverError::invalid_input(&format!("Workflow {} jumps to unknown workflow {}", workflow.name, name)));
+                }
+            }
+        }
+    }
+
+    return Ok(());
+}
+
+fn true_result_code_to_value(value: &str) -> RuleResult {
+    return if value == "A" {
+        Accept
+    } else if value == "R" {
+        Reject
+    } else {
+        GoToWorkflow(value.to_string())
+    };
+}
+
+fn get_all_accepted_parts(all_workflows: &HashMap<String, Workflow>) -> Vec<AggregatePart> {
+    let initial_workflow = all_workflows[STARTING_WORKFLOW_NAME].clone();
+    let initial_rule_index = 0usize;
+    let initial_parts = AggregatePart::all_parts();
+    let mut all_passing_parts = Vec::<AggregatePart>::new();
+
+    let mut evaluation_queue = VecDeque::<(RuleResult, Workflow, usize, AggregatePart)>::new();
+    evaluation_queue.extend(evaluate_rule(initial_workflow.clone(), initial_rule_index, initial_parts.clone(), all_workflows));
+
+    while !evaluation_queue.is_empty() {
+        let status = evaluation_queue.pop_front().unwrap();
+
+        if discriminant(&status.0) == discriminant(&Accept) {
+            all_passing_parts.push(status.3);
+        } else if discriminant(&status.0) == discriminant(&GoToWorkflow("".to_string())) || discriminant(&status.0) == discriminant(&NextRule) {
+            evaluation_queue.extend(evaluate_rule(status.1.clone(), status.2, status.3.clone(), all_workflows));
+        }
+    }
+
+    return all_passing_parts;
+}
+
+fn evaluate_rule(workflow: Workflow, rule_index: usize, input_parts: AggregatePart,
+                 all_workflows: &HashMap<String, Workflow>) -> Vec<(RuleResult, Workflow, usize, AggregatePart)>
+{
+    let rule = workflow.rules[rule_index].clone();
+    let evaluation_results = rule.evaluate(&input_parts);
+    let mut statuses = Vec::<(RuleResult, Workflow, usize, AggregatePart)>::new();
+
+    for result in evaluation_results {
+        match &result.0 {
+            GoToWorkflow(workflow_name) => {
+                statuses.push((result.0.clone(), all_workflows[workflow_name].clone(), 0usize, result.1))
+            },
+            _ => {
+                statuses.push((result.0.clone(), workflow.clone(), rule_index + 1, result.1))
+            },
+        }
+    }
+
+    return statuses;
+}
@@ -0,0 +1,40 @@
+#![allow(clippy::needless_return)]
+use crate::answer::Answer;
+use crate::error::SolverError;
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //Read data
+    let lines_iter = input
+        .lines()
+        .map(String::from);
+
+    //Parse and add calibration values to container
+    let mut calibration_values = Vec::<u32>::new();
+
+    for (line_index, line) in lines_iter.enumerate() {
+        let mut num1 : Option<u32> = None;
+        let mut num2 : Option<u32> = None;
+
+        for char in line.chars() {
+            if let Some(digit) = char.to_digit(10) {
+                num1 = Some(digit);
+                break;
+            }
+        }
+
+        for char in line.chars().rev() {
+            if let Some(digit) = char.to_digit(10) {
+                num2 = Some(digit);
+                break;
+            }
+        }
+
+       if num1.is_none() || num2.is_none() {
+           return Err(SolverError::parse_line(line_index, &line, "Line has no digits"));
+       }
+
+       calibration_values.push(10 * num1.unwrap() + num2.unwrap());
+   }
+
+    return Ok(Answer::from(calibration_values.iter().sum::<u32>()));
+}
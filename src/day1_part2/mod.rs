@@ -0,0 +1,80 @@
+#![allow(clippy::needless_return)]
+use crate::answer::Answer;
+use crate::error::SolverError;
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //Read data
+    let lines_iter = input
+        .lines()
+        .map(String::from);
+
+    //Parse and add calibration values to container
+    let mut calibration_values = Vec::<u32>::new();
+    for (line_index, line) in lines_iter.enumerate() {
+        let mut parsed_digits = Vec::<u32>::new();
+        let mut cursor_index = 0usize;
+
+        while cursor_index < line.len() {
+            let char = line.chars().nth(cursor_index).unwrap();
+            if let Some(digit) = char.to_digit(10) {
+                parsed_digits.push(digit);
+            } else {
+                try_parse_digit_string(cursor_index, &line, &mut parsed_digits);
+            }
+
+            cursor_index += 1
+        }
+
+        match (parsed_digits.first(), parsed_digits.last()) {
+            (Some(first), Some(last)) => calibration_values.push(10 * first + last),
+            _ => return Err(SolverError::parse_line(line_index, &line, "Line has no digits"))
+        }
+    }
+
+    //Sum results
+    return Ok(Answer::from(calibration_values.iter().sum::<u32>()));
+}
+
+fn try_parse_digit_string(cursor_index: usize, line: &str, parsed_digits: &mut Vec::<u32>) {
+    const MAX_BUFFER_LENGTH : usize = 5;
+    let mut digit_char_buffer = Vec::<char>::new();
+    let mut break_early = false;
+    let mut relative_cursor_index = 0usize;
+
+    while relative_cursor_index < MAX_BUFFER_LENGTH &&
+        cursor_index + relative_cursor_index < line.len() &&
+        !break_early {
+
+        let char = line.chars().nth(cursor_index + relative_cursor_index).unwrap();
+        if char.is_ascii_digit() {
+            break_early = true;
+        } else {
+            digit_char_buffer.push(char);
+
+            match parse_digit_char_buffer(&digit_char_buffer.iter().collect::<String>()) {
+                Ok(digit) => {
+                    parsed_digits.push(digit);
+                    break_early = true;
+                },
+                Err(_e) => {}
+            }
+        }
+
+        relative_cursor_index += 1;
+    }
+}
+
+fn parse_digit_char_buffer(input: &str) -> Result<u32, &'static str> {
+    return match input {
+        "one" => Ok(1),
+        "two" => Ok(2),
+        "three" => Ok(3),
+        "four" => Ok(4),
+        "five" => Ok(5),
+        "six" => Ok(6),
+        "seven" => Ok(7),
+        "eight" => Ok(8),
+        "nine" => Ok(9),
+        _ => Err("Not a valid digit string")
+    };
+}
\ No newline at end of file
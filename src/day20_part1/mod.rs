@@ -0,0 +1,123 @@
+#![allow(clippy::needless_return)]
+use std::collections::{HashMap, VecDeque};
+use std::collections::hash_map::Entry;
+
+use crate::day20_part1::modules::*;
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::error::SolverError;
+
+pub mod modules;
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    let mut downstream_modules = parse_data(input)?;
+    end_parse_phase();
+
+    let mut low_pulse_count = 0u64;
+    let mut high_pulse_count = 0u64;
+
+    for _ in 0..1000 {
+        (low_pulse_count, high_pulse_count, downstream_modules) = process_one_cycle(low_pulse_count, high_pulse_count, downstream_modules);
+    }
+
+    return Ok(Answer::from(low_pulse_count * high_pulse_count));
+}
+
+fn parse_data(input: &str) -> Result<HashMap<String, Box<dyn PulseReceiver>>, SolverError> {
+    //Init
+    let mut modules = HashMap::<String, Box<dyn PulseReceiver>>::new();
+    let mut io_map = HashMap::<String, Vec<String>>::new();
+    let mut conjunction_destinations = HashMap::<String, Vec<String>>::new();
+
+    //Closure for storing relationships. Needed later for updating inputs
+    //for conjunctions
+    let mut update_io_map = |source: &str, destinations: &Vec<String>| {
+        for destination in destinations {
+            if !io_map.contains_key(destination) {
+                io_map.insert(destination.clone(), vec![source.to_string()]);
+            } else {
+                io_map.get_mut(destination).unwrap().push(source.to_string());
+            }
+        }
+    };
+
+    //Define each module. Conjunctions are special and are only partially defined.
+    //They will be updated in a following step.
+    for (line_index, line) in input.lines().map(String::from).enumerate() {
+        let (module_type_definition, destinations) = parse_connection(line_index, &line)?;
+
+        if module_type_definition == Broadcaster::NAME {
+            modules.insert(Broadcaster::NAME.to_string(), Box::new(Broadcaster::new(&destinations)));
+            update_io_map(Broadcaster::NAME, &destinations);
+
+        } else if let Some(name) = module_type_definition.strip_prefix('%') {
+            modules.insert(name.to_string(), Box::new(FlipFlop::new(name, &destinations)));
+            update_io_map(name, &destinations);
+
+        } else if let Some(name) = module_type_definition.strip_prefix('&') {
+            update_io_map(name, &destinations);
+            conjunction_destinations.insert(name.to_string(), destinations);
+
+        } else {
+            return Err(SolverError::parse_token(line_index, &line, module_type_definition, "Invalid module type"));
+        }
+    }
+
+    //Create conjunction modules
+    for (name, destinations) in &conjunction_destinations {
+        let inputs = io_map.get(name).cloned().unwrap_or_default();
+        modules.insert(name.clone(), Box::new(Conjunction::new(name, &inputs, destinations)));
+    }
+
+    return Ok(modules);
+}
+
+//Splits "<module> -> <destination>, <destination>, ..." into the module definition and its destinations
+fn parse_connection(line_index: usize, line: &str) -> Result<(&str, Vec<String>), SolverError> {
+    let Some((module_type_definition, destinations)) = line.split_once("->") else {
+        return Err(SolverError::parse_line(line_index, line, "Expected a module followed by '->'"));
+    };
+
+    let destinations = destinations
+        .split(",")
+        .map(|x| x.trim().to_string())
+        .collect::<Vec<String>>();
+    if destinations.iter().any(|x| x.is_empty()) {
+        return Err(SolverError::parse_line(line_index, line, "Module has an empty destination"));
+    }
+
+    return Ok((module_type_definition.trim(), destinations));
+}
+
+fn process_one_cycle(initial_low_pulse_count: u64, initial_high_pulse_count: u64, mut downstream_modules: HashMap<String, Box<dyn PulseReceiver>>) -> (u64, u64, HashMap<String, Box<dyn PulseReceiver>>) {
+    let mut low_pulse_count = initial_low_pulse_count;
+    let mut high_pulse_count = initial_high_pulse_count;
+    let mut pulse_queue = VecDeque::<PulseOutput>::new();
+
+    //We always start with a single assumed button module and then go from there
+    let button_module = Button {};
+    let (low_pulses_generated, high_pulses_generated, output_pulses) = button_module.push();
+    low_pulse_count += low_pulses_generated;
+    high_pulse_count += high_pulses_generated;
+    pulse_queue.extend(output_pulses);
+
+    //Keep processing pulses as long as they are being generated. Process in FIFO order per
+    //problem statement. There are module sinks that can exist. We can detect these by noticing
+    //that there is no downstream module registered with the given name.
+    while !pulse_queue.is_empty() {
+        let pulse = pulse_queue.pop_front().unwrap();
+        let (low_pulses_generated, high_pulses_generated, output_pulses) = match downstream_modules.entry(pulse.1) {
+            Entry::Occupied(o) => {
+                let destination = o.into_mut();
+                destination.process_input_pulse(&pulse.0, pulse.2)
+            },
+            Entry::Vacant(_) => (0u64, 0u64, vec![])
+        };
+
+        low_pulse_count += low_pulses_generated;
+        high_pulse_count += high_pulses_generated;
+        pulse_queue.extend(output_pulses);
+    }
+
+    return (low_pulse_count, high_pulse_count, downstream_modules);
+}
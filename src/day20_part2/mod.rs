@@ -0,0 +1,171 @@
+#![allow(clippy::needless_return)]
+use std::collections::{HashMap, VecDeque};
+use std::collections::hash_map::Entry;
+use num::integer::lcm;
+
+use crate::day20_part2::modules::*;
+use crate::day20_part2::modules::PulseType::High;
+use crate::answer::Answer;
+use crate::error::SolverError;
+
+pub mod modules;
+
+const FINAL_MODULE_NAME: &str = "rx";
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //rx is fed by a single conjunction, so it receives a low pulse on the first button press where
+    //every input to that conjunction emits a high pulse. Each input repeats on its own cycle, so the
+    //answer is the LCM of the cycle lengths.
+    let mut num_button_presses = 1u64;
+
+    for module_name in &find_target_module_names(input)? {
+        let downstream_modules = parse_data(input)?;
+        let num_iterations = get_iteration_to_single_emitted_high_pulse(module_name, downstream_modules);
+        num_button_presses = lcm(num_button_presses, num_iterations);
+    }
+
+    return Ok(Answer::from(num_button_presses));
+}
+
+fn find_target_module_names(input: &str) -> Result<Vec<String>, SolverError> {
+    let connections = input
+        .lines()
+        .enumerate()
+        .map(|(line_index, line)| {
+            let (module_type_definition, destinations) = parse_connection(line_index, line)?;
+            let name = module_type_definition.trim_start_matches(['%', '&']).to_string();
+            return Ok((name, destinations));
+        })
+        .collect::<Result<Vec<(String, Vec<String>)>, SolverError>>()?;
+
+    let Some(rx_source) = connections
+        .iter()
+        .find(|x| x.1.iter().any(|y| y == FINAL_MODULE_NAME))
+        .map(|x| &x.0) else {
+        return Err(SolverError::invalid_input("No module sends pulses to rx"));
+    };
+
+    return Ok(connections
+        .iter()
+        .filter(|x| x.1.contains(rx_source))
+        .map(|x| x.0.clone())
+        .collect());
+}
+
+fn parse_data(input: &str) -> Result<HashMap<String, Box<dyn PulseReceiver>>, SolverError> {
+    //Init
+    let mut modules = HashMap::<String, Box<dyn PulseReceiver>>::new();
+    let mut io_map = HashMap::<String, Vec<String>>::new();
+    let mut conjunction_destinations = HashMap::<String, Vec<String>>::new();
+
+    //Closure for storing relationships. Needed later for updating inputs
+    //for conjunctions
+    let mut update_io_map = |source: &str, destinations: &Vec<String>| {
+        for destination in destinations {
+            if !io_map.contains_key(destination) {
+                io_map.insert(destination.clone(), vec![source.to_string()]);
+            } else {
+                io_map.get_mut(destination).unwrap().push(source.to_string());
+            }
+        }
+    };
+
+    //Define each module. Conjunctions are special and are only partially defined.
+    //They will be updated in a following step.
+    for (line_index, line) in input.lines().map(String::from).enumerate() {
+        let (module_type_definition, destinations) = parse_connection(line_index, &line)?;
+
+        if module_type_definition == Broadcaster::NAME {
+            modules.insert(Broadcaster::NAME.to_string(), Box::new(Broadcaster::new(&destinations)));
+            update_io_map(Broadcaster::NAME, &destinations);
+
+        } else if let Some(name) = module_type_definition.strip_prefix('%') {
+            modules.insert(name.to_string(), Box::new(FlipFlop::new(name, &destinations)));
+            update_io_map(name, &destinations);
+
+        } else if let Some(name) = module_type_definition.strip_prefix('&') {
+            update_io_map(name, &destinations);
+            conjunction_destinations.insert(name.to_string(), destinations);
+
+        } else {
+            return Err(SolverError::parse_token(line_index, &line, module_type_definition, "Invalid module type"));
+        }
+    }
+
+    //Without a broadcaster the button's pulse goes nowhere and the cycle search would never finish
+    if !modules.contains_key(Broadcaster::NAME) {
+        return Err(SolverError::invalid_input("There is no broadcaster module"));
+    }
+
+    //Create conjunction modules
+    for (name, destinations) in &conjunction_destinations {
+        let inputs = io_map.get(name).cloned().unwrap_or_default();
+        modules.insert(name.clone(), Box::new(Conjunction::new(name, &inputs, destinations)));
+    }
+
+    return Ok(modules);
+}
+
+//Splits "<module> -> <destination>, <destination>, ..." into the module definition and its destinations
+fn parse_connection(line_index: usize, line: &str) -> Result<(&str, Vec<String>), SolverError> {
+    let Some((module_type_definition, destinations)) = line.split_once("->") else {
+        return Err(SolverError::parse_line(line_index, line, "Expected a module followed by '->'"));
+    };
+
+    let destinations = destinations
+        .split(",")
+        .map(|x| x.trim().to_string())
+        .collect::<Vec<String>>();
+    if destinations.iter().any(|x| x.is_empty()) {
+        return Err(SolverError::parse_line(line_index, line, "Module has an empty destination"));
+    }
+
+    return Ok((module_type_definition.trim(), destinations));
+}
+
+fn process_one_cycle(target_module_name: &String, mut downstream_modules: HashMap<String, Box<dyn PulseReceiver>>) -> (bool, HashMap<String, Box<dyn PulseReceiver>>) {
+    let mut num_target_module_high_pulse = 0u64;
+    let mut pulse_queue = VecDeque::<PulseOutput>::new();
+
+    //We always start with a single assumed button module and then go from there
+    let button_module = Button {};
+    let (_, _, output_pulses) = button_module.push();
+    pulse_queue.extend(output_pulses);
+
+    //Keep processing pulses as long as they are being generated. Process in FIFO order per
+    //problem statement. There are module sinks that can exist. We can detect these by noticing
+    //that there is no downstream module registered with the given name.
+    while !pulse_queue.is_empty() {
+        let pulse = pulse_queue.pop_front().unwrap();
+        if &pulse.0 == target_module_name && pulse.2 == High {
+            num_target_module_high_pulse += 1;
+        }
+
+        let (_, _, output_pulses) = match downstream_modules.entry(pulse.1) {
+            Entry::Occupied(o) => {
+                let destination = o.into_mut();
+                destination.process_input_pulse(&pulse.0, pulse.2)
+            },
+            Entry::Vacant(_) => {
+                (0u64, 0u64, vec![])
+            }
+        };
+
+        pulse_queue.extend(output_pulses);
+    }
+
+    let single_high_emitted_from_target = num_target_module_high_pulse == 1;
+    return (single_high_emitted_from_target, downstream_modules);
+}
+
+fn get_iteration_to_single_emitted_high_pulse(target_module_name: &String, mut downstream_modules: HashMap<String, Box<dyn PulseReceiver>>) -> u64 {
+    let mut num_iterations = 0u64;
+    let mut single_high_sent = false;
+
+    while !single_high_sent {
+        (single_high_sent, downstream_modules) = process_one_cycle(target_module_name, downstream_modules);
+        num_iterations += 1;
+    }
+
+    return num_iterations;
+}
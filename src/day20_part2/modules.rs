@@ -1,3 +1,4 @@
+#![allow(clippy::needless_return, clippy::ptr_arg)]
 use std::collections::{HashMap};
 use std::fmt::Debug;
 use std::ops::Not;
@@ -0,0 +1,94 @@
+#![allow(clippy::needless_return)]
+use std::collections::{HashSet};
+use itertools::Itertools;
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::error::SolverError;
+use crate::grid::Grid;
+
+const START: char = 'S';
+const ROCK: char = '#';
+const GARDEN_PLOT: char = '.';
+const MAX_NUM_STEPS: u64 = 64;
+
+//Convention: x-axis positive right, y-axis positive down
+type Coordinate = (usize, usize);
+
+#[derive(Eq, PartialEq, Hash, Clone)]
+struct WalkState {
+    pub position: Coordinate,
+    pub steps_left: u64,
+}
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //Parse map
+    let mut map = parse_data(input)?;
+    end_parse_phase();
+
+    //Record starting position. Per problem statement, this is a garden
+    //spot, so update to make our lives easier later on.
+    let starting_position = find_starting_position(&map)?;
+    map[starting_position] = GARDEN_PLOT;
+
+    //Solve using dynamic programming
+    let num_positions = get_possible_num_positions(starting_position, &map);
+    return Ok(Answer::from(num_positions));
+}
+
+fn parse_data(input: &str) -> Result<Grid<char>, SolverError> {
+    return Grid::parse(input, |x| (x == START || x == ROCK || x == GARDEN_PLOT).then_some(x));
+}
+
+fn find_starting_position(data: &Grid<char>) -> Result<Coordinate, SolverError> {
+    return data
+        .position(|&x| x == START)
+        .ok_or_else(|| SolverError::invalid_input("Couldn't find the starting position"));
+}
+
+fn get_possible_num_positions(initial_position: Coordinate, map: &Grid<char>) -> usize {
+    let initial_state = WalkState { position: initial_position, steps_left: MAX_NUM_STEPS };
+    let mut final_positions = HashSet::<Coordinate>::new();
+    let mut solved_states = HashSet::<WalkState>::new();
+
+    let mut work_stack = Vec::<WalkState>::new();
+    work_stack.push(initial_state.clone());
+
+    while let Some(next_work_item) = work_stack.pop() {
+        
+        let additional_work_items = walk(next_work_item, &mut final_positions, &mut solved_states, map);
+        work_stack.extend(additional_work_items.into_iter().rev());
+    }
+
+    return final_positions.len();
+}
+
+fn walk(state: WalkState, final_positions: &mut HashSet<Coordinate>, solved_states: &mut HashSet<WalkState>, map: &Grid<char>) -> Vec<WalkState> {
+    let mut queued_work_items = vec![];
+
+    if state.steps_left == 0 {
+        final_positions.insert(state.position);
+        solved_states.insert(state.clone());
+    } else {
+        let next_positions = get_next_positions(state.position, map);
+        for next_state in next_positions.iter().map(|p| WalkState {position: *p, steps_left: state.steps_left - 1 }) {
+            if !solved_states.contains(&next_state) {
+                queued_work_items.push(next_state);
+            }
+        }
+
+        if !queued_work_items.is_empty() {
+            queued_work_items.push(state); //don't forgot to re-add ourselves if we are not done
+        } else {
+            solved_states.insert(state.clone());
+        }
+    }
+
+    return queued_work_items;
+}
+
+fn get_next_positions(current_position: Coordinate, map: &Grid<char>) -> Vec<Coordinate> {
+    return map
+        .neighbors4(current_position)
+        .filter(|&p| map[p] != ROCK)
+        .collect_vec();
+}
@@ -0,0 +1,124 @@
+#![allow(clippy::needless_return)]
+use std::collections::{HashSet};
+use itertools::Itertools;
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::direction::*;
+use crate::error::SolverError;
+use crate::grid::Grid;
+
+const NUM_STEPS: u64 = 26_501_365;
+const START: char = 'S';
+const ROCK: char = '#';
+const GARDEN_PLOT: char = '.';
+
+//Convention: x-axis positive right, y-axis positive down
+type UnsignedCoordinate = (usize, usize);
+type SignedCoordinate = SignedPosition;
+
+#[derive(Eq, PartialEq, Hash, Clone)]
+struct WalkState {
+    pub map_position: SignedCoordinate,
+    pub tile_position: UnsignedCoordinate,
+    pub steps_left: u64,
+}
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    let (map, starting_position) = parse_map(input)?;
+    end_parse_phase();
+
+    //The start is in the middle of a clear row and column, so the reachable count grows quadratically in
+    //the number of whole map widths walked. Sample three step counts with the same remainder as the
+    //target and extrapolate using forward differences.
+    let map_size = map.get_height() as u64;
+    let remainder = NUM_STEPS % map_size;
+    let samples = (0..3)
+        .map(|i| get_possible_num_positions(starting_position, remainder + i*map_size, &map) as i64)
+        .collect::<Vec<i64>>();
+
+    let num_map_widths = ((NUM_STEPS - remainder) / map_size) as i64;
+    let first_difference = samples[1] - samples[0];
+    let second_difference = samples[2] - 2*samples[1] + samples[0];
+    let num_positions = samples[0] + num_map_widths*first_difference + num_map_widths*(num_map_widths - 1)/2*second_difference;
+
+    return Ok(Answer::from(num_positions));
+}
+
+//Counts the garden plots reachable in exactly num_steps steps on the infinitely repeating map
+pub fn count_reachable_plots(input: &str, num_steps: u64) -> Result<usize, SolverError> {
+    let (map, starting_position) = parse_map(input)?;
+    return Ok(get_possible_num_positions(starting_position, num_steps, &map));
+}
+
+fn parse_map(input: &str) -> Result<(Grid<char>, UnsignedCoordinate), SolverError> {
+    let mut map = parse_data(input)?;
+
+    //Record starting tile_position. Per problem statement, this is a garden
+    //spot, so update to make our lives easier later on.
+    let starting_position = find_starting_position(&map)?;
+    map[starting_position] = GARDEN_PLOT;
+
+    return Ok((map, starting_position));
+}
+
+fn parse_data(input: &str) -> Result<Grid<char>, SolverError> {
+    return Grid::parse(input, |x| (x == START || x == ROCK || x == GARDEN_PLOT).then_some(x));
+}
+
+fn find_starting_position(data: &Grid<char>) -> Result<UnsignedCoordinate, SolverError> {
+    return data
+        .position(|&x| x == START)
+        .ok_or_else(|| SolverError::invalid_input("Couldn't find the starting position"));
+}
+
+fn get_possible_num_positions(initial_position: UnsignedCoordinate, max_steps: u64, map: &Grid<char>) -> usize {
+    let initial_state = WalkState {map_position: to_signed_position(initial_position),  tile_position: initial_position, steps_left: max_steps };
+    let initial_visited = HashSet::<SignedCoordinate>::new();
+    let mut final_positions = HashSet::<SignedCoordinate>::new();
+    let mut solved_states = HashSet::<WalkState>::new();
+
+    let mut work_queue = Vec::<(WalkState, HashSet<SignedCoordinate>)>::new();
+    work_queue.push((initial_state.clone(), initial_visited));
+
+    while let Some(next_work_item) = work_queue.pop() {
+        
+        let additional_work_items = walk(next_work_item.0, next_work_item.1, &mut final_positions, &mut solved_states, map);
+        work_queue.extend(additional_work_items.into_iter().rev());
+    }
+
+    return final_positions.len();
+}
+
+fn walk(state: WalkState, visited: HashSet<SignedCoordinate>, final_positions: &mut HashSet<SignedCoordinate>, solved_states: &mut HashSet<WalkState>, map: &Grid<char>) -> Vec<(WalkState, HashSet<SignedCoordinate>)> {
+    let mut queued_work_items = vec![];
+
+    if state.steps_left == 0 {
+        final_positions.insert(state.map_position);
+        solved_states.insert(state.clone());
+    } else {
+        let next_positions = get_next_positions(state.map_position, &visited, map);
+        for next_state in next_positions.iter().map(|p| WalkState { map_position: p.0, tile_position: p.1, steps_left: state.steps_left - 1 }) {
+            if !solved_states.contains(&next_state) {
+                queued_work_items.push((next_state, visited.clone()));
+            }
+        }
+
+        if !queued_work_items.is_empty() {
+            queued_work_items.push((state, visited.clone())); //don't forgot to re-add ourselves if we are not done
+        } else {
+            solved_states.insert(state.clone());
+        }
+    }
+
+    return queued_work_items;
+}
+
+fn get_next_positions(current_map_position: SignedCoordinate, visited: &HashSet<SignedCoordinate>, map: &Grid<char>) -> Vec<(SignedCoordinate, UnsignedCoordinate)> {
+    return Direction::ALL
+        .into_iter()
+        .map(|x| x.advance(current_map_position))
+        .map(|x| (x, map.wrap(x.0, x.1)))
+        .filter(|p| !visited.contains(&p.0))
+        .filter(|p| map[p.1] != ROCK)
+        .collect_vec();
+}
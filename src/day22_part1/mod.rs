@@ -0,0 +1,152 @@
+#![allow(clippy::needless_return, clippy::ptr_arg)]
+pub mod brick;
+
+use std::collections::{HashMap};
+use brick::Orientation;
+use crate::day22_part1::brick::Brick;
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::error::*;
+
+type Coordinate = (u64, u64, u64);
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //Parse data and init world map
+    let mut bricks = parse_data(input)?;
+    end_parse_phase();
+    let mut world_map = HashMap::<Coordinate, u32>::new();
+    initialize_world_map(&bricks, &mut world_map);
+
+    //Let bricks fall and then disintegrate
+    get_final_positions(&mut bricks, &mut world_map);
+    let num_bricks_can_be_removed = bricks
+        .iter()
+        .map(|x| brick_can_be_disintegrated(x, &bricks, &mut world_map))
+        .filter(|x| *x)
+        .count();
+
+    return Ok(Answer::from(num_bricks_can_be_removed));
+}
+
+fn parse_data(input: &str) -> Result<Vec<Brick>, SolverError> {
+    return input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let (start_position, end_position) = parse_positions(index, line)?;
+            return Ok(Brick::new(index as u32, start_position, end_position));
+        })
+        .collect();
+}
+
+fn parse_positions(line_index: usize, line: &str) -> Result<(Coordinate, Coordinate), SolverError> {
+    let Some((start, end)) = line.split_once("~") else {
+        return Err(SolverError::parse_line(line_index, line, "Expected two positions separated by '~'"));
+    };
+    let parse_position = |position: &str| -> Result<Coordinate, SolverError> {
+        let terms = position
+            .split(",")
+            .map(|term| parse_token::<u64>(line_index, line, term))
+            .collect::<Result<Vec<u64>, SolverError>>()?;
+
+        return match terms.as_slice() {
+            [x, y, z] => Ok((*x, *y, *z)),
+            _ => Err(SolverError::parse_token(line_index, line, position, "Position must have three coordinates"))
+        };
+    };
+    let start_position = parse_position(start)?;
+    let end_position = parse_position(end)?;
+
+    //The falling logic assumes bricks are straight lines with their start at the lowest coordinate, above the ground
+    let num_differing_axes = [start_position.0 != end_position.0, start_position.1 != end_position.1, start_position.2 != end_position.2]
+        .iter()
+        .filter(|x| **x)
+        .count();
+    if num_differing_axes > 1 {
+        return Err(SolverError::parse_line(line_index, line, "Brick must be a straight line"));
+    }
+    if start_position.0 > end_position.0 || start_position.1 > end_position.1 || start_position.2 > end_position.2 {
+        return Err(SolverError::parse_line(line_index, line, "Brick must start at its lowest coordinate"));
+    }
+    if start_position.2 == 0 {
+        return Err(SolverError::parse_token(line_index, line, start, "Brick can't be below the ground"));
+    }
+
+    return Ok((start_position, end_position));
+}
+
+fn initialize_world_map(bricks: &Vec<Brick>, world_map: &mut HashMap<Coordinate, u32>) {
+    for brick in bricks {
+        insert_brick(brick, world_map);
+    }
+}
+
+fn insert_brick(brick: &Brick, world_map: &mut HashMap<Coordinate, u32>) {
+    match brick.orientation {
+        Orientation::X => {
+            for x in brick.start_position.0..=brick.end_position.0 {
+                world_map.insert((x, brick.start_position.1, brick.start_position.2), brick.id);
+            }
+        },
+        Orientation::Y => {
+            for y in brick.start_position.1..=brick.end_position.1 {
+                world_map.insert((brick.start_position.0, y, brick.start_position.2), brick.id);
+            }
+        },
+        Orientation::Z => {
+            for z in brick.start_position.2..=brick.end_position.2 {
+                world_map.insert((brick.start_position.0, brick.start_position.1, z), brick.id);
+            }
+        }
+    };
+}
+
+fn remove_brick(brick: &Brick, world_map: &mut HashMap<Coordinate, u32>) {
+    match brick.orientation {
+        Orientation::X => {
+            for x in brick.start_position.0..=brick.end_position.0 {
+                world_map.remove(&(x, brick.start_position.1, brick.start_position.2));
+            }
+        },
+        Orientation::Y => {
+            for y in brick.start_position.1..=brick.end_position.1 {
+                world_map.remove(&(brick.start_position.0, y, brick.start_position.2));
+            }
+        },
+        Orientation::Z => {
+            for z in brick.start_position.2..=brick.end_position.2 {
+                world_map.remove(&(brick.start_position.0, brick.start_position.1, z));
+            }
+        }
+    };
+}
+
+fn get_final_positions(bricks: &mut Vec<Brick>, world_map: &mut HashMap<Coordinate, u32>) {
+    let mut brick_moved = true;
+
+    while brick_moved {
+        brick_moved = false;
+
+        for brick in &mut *bricks {
+            if brick.can_fall_one(world_map) {
+                brick.fall(1, world_map);
+                brick_moved = true;
+            }
+        }
+    }
+}
+
+fn brick_can_be_disintegrated(test_brick: &Brick, bricks: &Vec<Brick>, world_map: &mut HashMap<Coordinate, u32>) -> bool {
+    let mut can_be_removed = true;
+    remove_brick(test_brick, world_map);
+
+    for other_brick in bricks.iter().filter(|&x| *x != *test_brick) {
+        if other_brick.can_fall_one(world_map) {
+            can_be_removed = false;
+            break;
+        }
+    }
+
+    insert_brick(test_brick, world_map);
+    return can_be_removed;
+}
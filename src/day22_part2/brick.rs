@@ -1,5 +1,6 @@
+#![allow(clippy::needless_return)]
 use std::collections::HashMap;
-use crate::Coordinate;
+use crate::day22_part2::Coordinate;
 
 #[derive(Eq, PartialEq, Hash, Clone)]
 pub enum Orientation {
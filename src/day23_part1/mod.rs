@@ -0,0 +1,126 @@
+#![allow(clippy::needless_return, clippy::type_complexity)]
+use std::collections::{HashMap, HashSet};
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::direction::Direction;
+use crate::direction::Direction::*;
+use crate::error::SolverError;
+use crate::grid::Grid;
+
+const PATH: char = '.';
+const FOREST: char = '#';
+const UP_SLOPE: char = '^';
+const RIGHT_SLOPE: char = '>';
+const DOWN_SLOPE: char = 'v';
+const LEFT_SLOPE: char = '<';
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    let map = parse_data(input)?;
+    end_parse_phase();
+    let (start_pos, end_pos) = find_terminal_positions(&map)?;
+    let max_steps = get_max_steps(&start_pos, &end_pos, &map);
+
+    return Ok(Answer::from(max_steps));
+}
+
+fn parse_data(input: &str) -> Result<Grid<char>, SolverError> {
+    return Grid::parse(input, |x| [PATH, FOREST, UP_SLOPE, RIGHT_SLOPE, DOWN_SLOPE, LEFT_SLOPE].contains(&x).then_some(x));
+}
+
+fn find_terminal_positions(map: &Grid<char>) -> Result<((usize, usize), (usize, usize)), SolverError> {
+    //Per problem definition, the start and end positions have exactly one path object in each row
+    let Some(start_col) = map.row(0).iter().position(|c| *c == PATH) else {
+        return Err(SolverError::invalid_input("There is no path tile in the first row"));
+    };
+    let Some(end_col) = map.row(map.get_height() - 1).iter().position(|c| *c == PATH) else {
+        return Err(SolverError::invalid_input("There is no path tile in the last row"));
+    };
+
+    return Ok(((0, start_col), (map.get_height() - 1, end_col)));
+}
+
+fn get_valid_moves(position: &(usize, usize), map: &Grid<char>, path: &HashSet<(usize, usize)>) -> Vec<((usize, usize), Direction)> {
+    let directions = match map[*position] {
+        UP_SLOPE => vec![North],
+        RIGHT_SLOPE => vec![East],
+        DOWN_SLOPE => vec![South],
+        LEFT_SLOPE => vec![West],
+        _ => vec![West, East, North, South]
+    };
+
+    let mut valid_positions = Vec::<((usize, usize), Direction)>::new();
+
+    for direction in directions {
+        let Some(test_position) = map.step(*position, direction.get_delta()) else {
+            continue;
+        };
+
+        if map[test_position] != FOREST && !path.contains(&test_position) {
+            valid_positions.push((test_position, direction));
+        }
+    }
+
+    return valid_positions;
+}
+
+fn get_max_steps(start_pos: &(usize, usize), end_pos: &(usize, usize), map: &Grid<char>) -> usize {
+    const START_DIRECTION: Direction = South;
+    const END_DIRECTION: Direction = South;
+
+    let mut cache = HashMap::<((usize, usize), Direction), usize>::new();
+    let mut work_stack = Vec::<(((usize, usize), Direction), HashSet<(usize, usize)>)>::new();
+    let mut initial_path = HashSet::<(usize, usize)>::new();
+    initial_path.insert(*start_pos);
+    work_stack.push(((*start_pos, START_DIRECTION), initial_path));
+
+    while let Some(work_item) = work_stack.pop() {
+        
+
+        if cache.contains_key(&(work_item.0.0, work_item.0.1)) {
+            continue;
+        }
+
+        if work_item.0 == (*end_pos, END_DIRECTION) {
+            cache.insert(work_item.0, 1);
+        } else {
+            let mut max_substeps = 0usize;
+            let mut additional_work_items = vec![];
+
+            for next_position in get_valid_moves(&work_item.0.0, map, &work_item.1) {
+                if let Some(result) = cache.get(&next_position) {
+                    if *result > max_substeps {
+                        max_substeps = *result;
+                    }
+                } else {
+                    let mut path = work_item.1.clone();
+                    path.insert(work_item.0.0);
+                    additional_work_items.push((next_position, path));
+                }
+            }
+
+            // If the child paths have not been found, we need to requeue this spot to ensure
+            // this spot's max step count gets updated when all child paths have been found
+            if additional_work_items.is_empty() {
+                cache.insert(work_item.0, if max_substeps == 0 { 0 } else { max_substeps + 1 });
+            } else {
+                additional_work_items.push((work_item.0, work_item.1.clone()));
+                work_stack.extend(additional_work_items.into_iter().rev());
+            }
+        }
+    }
+
+    /*
+    for row in 0..map.get_height() {
+        for col in 0..map.get_width() {
+            for direction in Direction::ALL {
+                if let Some(result) = cache.get(&((row, col), direction)) {
+                    println!("({}, {}) = {}", row, col, result);
+                }
+            }
+        }
+    }
+     */
+
+    //The initial position doesn't count as a step so subtract 1
+    return cache[&(*start_pos, START_DIRECTION)] - 1;
+}
\ No newline at end of file
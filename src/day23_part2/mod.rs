@@ -0,0 +1,169 @@
+#![allow(clippy::needless_return, clippy::ptr_arg, clippy::type_complexity)]
+use std::collections::{HashMap, HashSet};
+use itertools::Itertools;
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::direction::Direction::*;
+use crate::error::SolverError;
+use crate::grid::Grid;
+
+const PATH: char = '.';
+const FOREST: char = '#';
+//Slopes can be walked in any direction so they are treated like paths
+const SLOPES: [char; 4] = ['^', '>', 'v', '<'];
+
+type WorkItem = ((usize, usize), Vec::<(usize, usize)>);
+
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    let map = parse_data(input)?;
+    end_parse_phase();
+    let (start_pos, end_pos) = find_terminal_positions(&map)?;
+    let preprocessed_graph = preprocess_graph(&map, &start_pos, &end_pos);
+    let max_steps = get_max_steps(&start_pos, &end_pos, &preprocessed_graph);
+
+    return Ok(Answer::from(max_steps));
+}
+
+fn parse_data(input: &str) -> Result<Grid<char>, SolverError> {
+    return Grid::parse(input, |x| (x == PATH || x == FOREST || SLOPES.contains(&x)).then_some(x));
+}
+
+fn find_terminal_positions(map: &Grid<char>) -> Result<((usize, usize), (usize, usize)), SolverError> {
+    //Per problem definition, the start and end positions have exactly one path object in each row
+    let Some(start_col) = map.row(0).iter().position(|c| *c == PATH) else {
+        return Err(SolverError::invalid_input("There is no path tile in the first row"));
+    };
+    let Some(end_col) = map.row(map.get_height() - 1).iter().position(|c| *c == PATH) else {
+        return Err(SolverError::invalid_input("There is no path tile in the last row"));
+    };
+
+    return Ok(((0, start_col), (map.get_height() - 1, end_col)));
+}
+
+
+
+fn get_max_steps(start_pos: &(usize, usize), end_pos: &(usize, usize), preprocessed_graph: &HashMap<((usize, usize), (usize, usize)), usize>) -> usize {
+    //DFS
+    let mut max_steps = 0usize;
+    let mut work_stack = Vec::<WorkItem>::new();
+    let initial_visited = Vec::<(usize, usize)>::new();
+    work_stack.extend(generate_work_items(start_pos, preprocessed_graph, &initial_visited));
+
+    while let Some(mut work_item) = work_stack.pop() {
+        
+
+        if work_item.0 == *end_pos {
+            work_item.1.push(work_item.0);
+
+            let mut path_total = 0usize;
+            for i in 0..work_item.1.len() - 1 {
+                path_total += preprocessed_graph[&(work_item.1[i], work_item.1[i + 1])]
+            }
+
+            if path_total > max_steps {
+                max_steps = path_total
+            }
+        } else {
+            work_stack.extend(generate_work_items(&work_item.0, preprocessed_graph, &work_item.1))
+        }
+    }
+
+    return max_steps;
+}
+
+fn generate_work_items(current_pos: &(usize, usize), preprocessed_graph: &HashMap<((usize, usize), (usize, usize)), usize>, visited: &Vec<(usize, usize)>) -> Vec<WorkItem> {
+    let mut work_items = Vec::<WorkItem>::new();
+
+    for valid_move in get_valid_moves_without_revisit(current_pos, preprocessed_graph, visited) {
+        let mut child_visited = visited.clone();
+        child_visited.push(*current_pos);
+        work_items.push((valid_move, child_visited));
+    }
+
+    return work_items;
+}
+
+fn preprocess_graph(map: &Grid<char>, start_pos: &(usize, usize), end_pos: &(usize, usize)) -> HashMap<((usize, usize), (usize, usize)), usize> {
+    let mut preprocess_graph = HashMap::<((usize, usize), (usize, usize)), usize>::new();
+    let junction_tiles = find_junction_tiles(map, start_pos, end_pos);
+
+    for tile in &junction_tiles {
+        update_preprocessed_graph(tile, &junction_tiles, map, &mut preprocess_graph);
+    }
+
+    return preprocess_graph;
+}
+
+fn find_junction_tiles(map: &Grid<char>, start_pos: &(usize, usize), end_pos: &(usize, usize)) -> HashSet<(usize, usize)> {
+    let mut junction_tiles = HashSet::<(usize, usize)>::new();
+
+    for position in map.positions() {
+        if map[position] != FOREST && (
+            position == *start_pos ||
+            position == *end_pos ||
+            get_valid_moves(&position, &None, map).len() > 2) {
+
+            junction_tiles.insert(position);
+        }
+    }
+
+    return junction_tiles;
+}
+
+fn update_preprocessed_graph(tile_position: &(usize, usize), junction_tiles: &HashSet<(usize, usize)>, map: &Grid<char>, path_lengths_map: &mut HashMap<((usize, usize), (usize, usize)), usize>) {
+    for first_step_position in get_valid_moves(tile_position, &None, map) {
+        let mut continue_walking_path = true;
+        let mut previous_step = *tile_position;
+        let mut current_step = first_step_position;
+        let mut step_count = 1usize;
+
+        while continue_walking_path {
+            if junction_tiles.contains(&current_step) {
+                if path_lengths_map.contains_key(&(*tile_position, current_step)) {
+                    if step_count > path_lengths_map[&(*tile_position, current_step)] {
+                        path_lengths_map.insert((*tile_position, current_step), step_count);
+                    }
+                } else {
+                    path_lengths_map.insert((*tile_position, current_step), step_count);
+                }
+
+                continue_walking_path = false;
+            } else {
+                let valid_moves = get_valid_moves(&current_step, &Some(previous_step), map);
+
+                match valid_moves.len() {
+                    0 => continue_walking_path = false,
+                    1 => {
+                        if valid_moves[0] == first_step_position {
+                            continue_walking_path  = false;
+                        } else {
+                            previous_step = current_step;
+                            current_step = valid_moves[0];
+                        }
+                    },
+                    _ => panic!("Logic error. This state should not be possible.")
+                }
+            }
+
+            step_count += 1;
+        }
+    }
+}
+
+fn get_valid_moves(position: &(usize, usize), previous_position: &Option<(usize, usize)>, map: &Grid<char>) -> Vec<(usize, usize)> {
+    return [West, East, North, South]
+        .into_iter()
+        .filter_map(|direction| map.step(*position, direction.get_delta()))
+        .filter(|test_position| map[*test_position] != FOREST && Some(*test_position) != *previous_position)
+        .collect_vec();
+}
+
+fn get_valid_moves_without_revisit(position: &(usize, usize), preprocessed_graph: &HashMap<((usize, usize), (usize, usize)), usize>, visited: &Vec<(usize, usize)>) -> Vec<(usize, usize)> {
+    return preprocessed_graph
+        .iter()
+        .filter(|entry| entry.0.0 == *position)
+        .map(|entry| entry.0.1)
+        .filter(|dest| !visited.contains(dest))
+        .collect_vec();
+}
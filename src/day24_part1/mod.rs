@@ -0,0 +1,90 @@
+#![allow(clippy::needless_return, clippy::ptr_arg, clippy::type_complexity)]
+use itertools::Itertools;
+
+use nalgebra::{Const, Matrix2, OMatrix, Vector2, Vector3};
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::error::*;
+
+const LOWER_BOUND: f64 = 200000000000000.0;
+const UPPER_BOUND: f64 = 400000000000000.0;
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    return Ok(Answer::from(count_intersections_in_bounds(input, LOWER_BOUND, UPPER_BOUND)?));
+}
+
+//Counts the future path intersections that fall inside the test area on both axes
+pub fn count_intersections_in_bounds(input: &str, lower_bound: f64, upper_bound: f64) -> Result<usize, SolverError> {
+    let data = parse_data(input)?;
+    end_parse_phase();
+    let time_results = get_time_results(&data.0, &data.1);
+    let intersection_results = get_intersection_results(data.0, data.1, time_results);
+    let filtered_intersections = intersection_results
+        .iter()
+        .filter(|&v| v.0 >= lower_bound && v.0 <= upper_bound && v.1 >= lower_bound && v.1 <= upper_bound)
+        .collect_vec();
+
+    return Ok(filtered_intersections.len());
+}
+
+fn parse_data(input: &str) -> Result<(Vec<Vector3<f64>>, Vec<Vector3<f64>>), SolverError> {
+    let mut positions = Vec::<Vector3<f64>>::new();
+    let mut velocities = Vec::<Vector3<f64>>::new();
+
+    for (line_index, line) in input.lines().enumerate() {
+        let Some((position, velocity)) = line.split_once("@") else {
+            return Err(SolverError::parse_line(line_index, line, "Expected a position and velocity separated by '@'"));
+        };
+
+        positions.push(parse_vector(line_index, line, position)?);
+        velocities.push(parse_vector(line_index, line, velocity)?);
+    }
+
+    if positions.is_empty() {
+        return Err(SolverError::invalid_input("Input is empty"));
+    }
+
+    return Ok((positions, velocities));
+}
+
+fn parse_vector(line_index: usize, line: &str, text: &str) -> Result<Vector3<f64>, SolverError> {
+    let terms = text
+        .split(",")
+        .map(|num| parse_token::<f64>(line_index, line, num))
+        .collect::<Result<Vec<f64>, SolverError>>()?;
+
+    if terms.len() != 3 {
+        return Err(SolverError::parse_token(line_index, line, text.trim(), "Expected three coordinates"));
+    }
+
+    return Ok(Vector3::from_vec(terms));
+}
+
+fn get_time_results(positions: &Vec<Vector3<f64>>, velocities: &Vec<Vector3<f64>>) -> Vec<((usize, usize), Option<OMatrix<f64, Const<2>, Const<1>>>)> {
+    let mut results = vec![];
+
+    for i in 0..positions.len() - 1{
+        for j in i + 1..positions.len() {
+            results.push(((i, j), solve_system(&positions[i], velocities[i], positions[j], &velocities[j])));
+        }
+    }
+
+    return results;
+}
+
+fn get_intersection_results(positions: Vec<Vector3<f64>>, velocities: Vec<Vector3<f64>>, time_results: Vec<((usize, usize), Option<OMatrix<f64, Const<2>, Const<1>>>)>) -> Vec<(f64, f64)> {
+    return time_results
+        .iter()
+        .filter(|pair| pair.1.is_some())
+        .filter(|pair| pair.1.unwrap()[0] >= 0.0 && pair.1.unwrap()[1] >= 0.0)
+        .map(|pair| positions[pair.0.0] + velocities[pair.0.0] * (pair.1.as_ref().unwrap()[0]))
+        .map(|x| (x[0], x[1]))
+        .collect_vec();
+}
+
+fn solve_system(p1: &Vector3<f64>, v1: Vector3<f64>, p2: Vector3<f64>, v2: &Vector3<f64>) -> Option<OMatrix<f64, Const<2>, Const<1>>> {
+    let b = Vector2::new(p2.x - p1.x, p2.y - p1.y );
+    let a = Matrix2::new(v1.x, -v2.x, v1.y, -v2.y);
+    let lu = a.lu();
+    return lu.solve(&b);
+}
\ No newline at end of file
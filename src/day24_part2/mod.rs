@@ -0,0 +1,92 @@
+#![allow(clippy::needless_return, clippy::type_complexity)]
+
+use nalgebra::{Const, Matrix6, OMatrix, Vector3, Vector6};
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::error::*;
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    let data = parse_data(input)?;
+    end_parse_phase();
+    if data.0.len() < 3 {
+        return Err(SolverError::invalid_input("At least three hailstones are needed to find the rock"));
+    }
+
+    let (p, _v) = solve_rock_data(&data.0[0], &data.1[0], &data.0[1], &data.1[1], &data.0[2], &data.1[2]);
+
+    return Ok(Answer::from(p.sum().round() as i64));
+}
+
+fn parse_data(input: &str) -> Result<(Vec<Vector3<f64>>, Vec<Vector3<f64>>), SolverError> {
+    let mut positions = Vec::<Vector3<f64>>::new();
+    let mut velocities = Vec::<Vector3<f64>>::new();
+
+    for (line_index, line) in input.lines().enumerate() {
+        let Some((position, velocity)) = line.split_once("@") else {
+            return Err(SolverError::parse_line(line_index, line, "Expected a position and velocity separated by '@'"));
+        };
+
+        positions.push(parse_vector(line_index, line, position)?);
+        velocities.push(parse_vector(line_index, line, velocity)?);
+    }
+
+    if positions.is_empty() {
+        return Err(SolverError::invalid_input("Input is empty"));
+    }
+
+    return Ok((positions, velocities));
+}
+
+fn parse_vector(line_index: usize, line: &str, text: &str) -> Result<Vector3<f64>, SolverError> {
+    let terms = text
+        .split(",")
+        .map(|num| parse_token::<f64>(line_index, line, num))
+        .collect::<Result<Vec<f64>, SolverError>>()?;
+
+    if terms.len() != 3 {
+        return Err(SolverError::parse_token(line_index, line, text.trim(), "Expected three coordinates"));
+    }
+
+    return Ok(Vector3::from_vec(terms));
+}
+
+#[allow(dead_code)]
+fn solve_system(ap: &Vector3<f64>, av: &Vector3<f64>, bp: &Vector3<f64>, bv: &Vector3<f64>, cp: &Vector3<f64>, cv: &Vector3<f64>) -> Option<OMatrix<f64, Const<6>, Const<1>>> {
+    let b = Vector6::<f64>::new(
+        ap.y*av.z - bp.y*bv.z - ap.z*av.y + bp.z*bv.y,
+        -ap.x*av.z + bp.x*bv.z - ap.z*av.x + bp.z*bv.x,
+        ap.x*av.y - bp.x*bv.y - ap.y*av.x + bp.y*bv.x,
+        ap.y*av.z - cp.y*cv.z - ap.z*av.y + cp.z*cv.y,
+        -ap.x*av.z + cp.x*cv.z - ap.z*av.x + cp.z*cv.x,
+        ap.x*av.y - cp.x*cv.y - ap.y*av.x + cp.y*cv.x,
+    );
+    
+    let a = Matrix6::<f64>::new(
+        0f64, av.z - bv.z, -av.y+bv.y, 0f64, -ap.z+bp.z, ap.y-bp.y,
+        -av.z+bv.z, 0f64, av.x - bv.x, ap.z - bp.z, 0f64, -ap.x + bp.x,
+        av.y - bv.y, -av.x + bv.x, 0f64, -ap.y + bp.y, ap.x - bp.x, 0f64,
+        0f64, av.z - cv.z, -av.y+cv.y, 0f64, -ap.z+cp.z, ap.y-cp.y,
+        -av.z+cv.z, 0f64, av.x - cv.x, ap.z - cp.z, 0f64, -ap.x + cp.x,
+        av.y - cv.y, -av.x + cv.x, 0f64, -ap.y + cp.y, ap.x - cp.x, 0f64
+    );
+    let lu = a.lu();
+    return lu.solve(&b);
+}
+
+fn solve_rock_data(hp0: &Vector3<f64>, hv0: &Vector3<f64>, hp1: &Vector3<f64>, hv1: &Vector3<f64>, hp2: &Vector3<f64>, hv2: &Vector3<f64>) -> (Vector3<f64>, Vector3<f64>) {
+    let p1 = hp1 - hp0;
+    let p2 = hp2 - hp0;
+    let v1 = hv1 - hv0;
+    let v2 = hv2 - hv0;
+
+    let t1 = -(p1.cross(&p2).dot(&v2)) / (v1.cross(&p2).dot(&v2));
+    let t2 = -(p1.cross(&p2).dot(&v1)) / (p1.cross(&v2).dot(&v1));
+
+    let c1 = hp1 + t1 * hv1;
+    let c2 = hp2 + t2 * hv2;
+
+    let v = (c2 - c1) / (t2 - t1);
+    let p = c1 - t1*v;
+
+    return (p, v);
+}
\ No newline at end of file
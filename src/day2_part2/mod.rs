@@ -0,0 +1,95 @@
+#![allow(clippy::needless_return, clippy::type_complexity)]
+use std::collections::HashMap;
+use regex::Regex;
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::error::*;
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //Read data
+    let lines_iter = input
+        .lines()
+        .map(String::from);
+
+    //Parse input
+    let all_games_summary = parse_results(lines_iter)?;
+    end_parse_phase();
+    //println!("{:?}", all_games_summary);
+
+    //Calculate result
+    let get_max_powers = |game_result: &Vec<(u32,u32,u32)>| -> (u32, u32, u32) {
+        let mut max_r = 0u32;
+        let mut max_g = 0u32;
+        let mut max_b = 0u32;
+
+        for round_values in game_result {
+            if round_values.0 > max_r {
+                max_r = round_values.0
+            }
+
+            if round_values.1 > max_g {
+                max_g = round_values.1
+            }
+
+            if round_values.2 > max_b {
+                max_b = round_values.2
+            }
+        }
+
+        return (max_r, max_g, max_b);
+    };
+
+    let sum_powers = all_games_summary.values().map(get_max_powers)
+        .map(|x| x.0 * x.1 * x.2)
+        .sum::<u32>();
+    return Ok(Answer::from(sum_powers));
+}
+
+fn parse_results(all_results_iter: impl Iterator<Item = String>) -> Result<HashMap::<u32, Vec<(u32, u32, u32)>>, SolverError> {
+    //Init
+    let game_id_regex = Regex::new(r#"Game (\d+)"#).unwrap();
+    let color_regex = Regex::new(r#"(\d+) (red|green|blue)"#).unwrap();
+    let mut all_games_summary = HashMap::<u32, Vec<(u32, u32, u32)>>::new();
+
+    //Parse each game
+    for (line_index, game_results) in all_results_iter.enumerate() {
+        let mut game_rounds_results = Vec::<(u32, u32, u32)>::new();
+
+        //Split between ID and game results
+        let Some((game_header, game_rounds)) = game_results.split_once(":") else {
+            return Err(SolverError::parse_line(line_index, &game_results, "Missing ':' after the game ID"));
+        };
+
+        //Get ID
+        let Some(id_match) = game_id_regex.captures(game_header) else {
+            return Err(SolverError::parse_token(line_index, &game_results, game_header, "Invalid game ID"));
+        };
+        let game_id = parse_token::<u32>(line_index, &game_results, id_match.get(1).unwrap().as_str())?;
+
+        //Get game results
+        for round_result in game_rounds.split(";") {
+            let mut rgb_values = (0u32, 0u32, 0u32);
+
+            for color_result in round_result.split(",") {
+                let Some(color_result_captures) = color_regex.captures(color_result) else {
+                    return Err(SolverError::parse_token(line_index, &game_results, color_result, "Invalid cube count"));
+                };
+                let color_count = parse_token::<u32>(line_index, &game_results, color_result_captures.get(1).unwrap().as_str())?;
+
+                match color_result_captures.get(2).unwrap().as_str() {
+                    "red" => rgb_values.0 = color_count,
+                    "green" => rgb_values.1 = color_count,
+                    "blue" => rgb_values.2 = color_count,
+                    _ => unreachable!("The regex only matches red, green or blue")
+                };
+
+            }
+
+            game_rounds_results.push(rgb_values);
+        }
+
+        all_games_summary.insert(game_id, game_rounds_results);
+    }
+
+    return Ok(all_games_summary);
+}
@@ -0,0 +1,78 @@
+#![allow(clippy::needless_return)]
+use itertools::izip;
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::error::SolverError;
+use crate::grid::*;
+
+#[repr(u8)]
+#[derive(Debug)]
+#[derive(Eq, PartialEq)]
+enum SchematicResult {
+    Noise = 0,
+    Digit = 1,
+    Symbol = 2
+}
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //Read data
+    let raw_data = Grid::parse(input, Some)?;
+
+    //Map data to more useful form
+    let processed_data = raw_data.map(map_symbol_to_enum);
+    end_parse_phase();
+
+    //Find valid digits
+    let valid_digits = processed_data.map_with_position(|position, item|
+        *item == SchematicResult::Digit && has_adjacent_symbol(&processed_data, position));
+
+    //Convert digits to valid number
+    let valid_numbers = extract_valid_numbers(&raw_data, &processed_data, &valid_digits);
+    return Ok(Answer::from(valid_numbers.iter().sum::<u32>()));
+}
+
+fn map_symbol_to_enum(symbol: &char) -> SchematicResult {
+    return if *symbol == '.' {
+        SchematicResult::Noise
+    } else if symbol.is_ascii_digit() {
+        SchematicResult::Digit
+    } else {
+        SchematicResult::Symbol
+    };
+}
+
+fn has_adjacent_symbol(processed_data: &Grid<SchematicResult>, test_coord: Position) -> bool {
+    return processed_data
+        .neighbors8(test_coord)
+        .any(|x| processed_data[x] == SchematicResult::Symbol);
+}
+
+fn extract_valid_numbers(raw_data: &Grid<char>, processed_data: &Grid<SchematicResult>, valid_digits: &Grid<bool>) -> Vec<u32> {
+    let mut valid_numbers = Vec::<u32>::new();
+
+    for i in 0..raw_data.get_height() {
+        let mut char_buffer = Vec::<char>::new();
+        let mut valid_digit_buffer = Vec::<bool>::new();
+
+        for item_summary in izip!(raw_data.row(i), processed_data.row(i), valid_digits.row(i)) {
+            if *item_summary.1 == SchematicResult::Digit {
+                char_buffer.push(*item_summary.0);
+                valid_digit_buffer.push(*item_summary.2);
+            } else {
+                if !char_buffer.is_empty() && valid_digit_buffer.iter().any(|x| *x) {
+                    valid_numbers.push(char_buffer.iter().collect::<String>().parse::<u32>().unwrap())
+                }
+
+                char_buffer.clear();
+                valid_digit_buffer.clear();
+            }
+        }
+
+        //Need to check after end in case final item is a digit
+        if !char_buffer.is_empty() && valid_digit_buffer.iter().any(|x| *x) {
+            valid_numbers.push(char_buffer.iter().collect::<String>().parse::<u32>().unwrap())
+        }
+    }
+
+    return valid_numbers;
+}
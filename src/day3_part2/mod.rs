@@ -0,0 +1,62 @@
+#![allow(clippy::needless_return)]
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::error::SolverError;
+use crate::grid::Grid;
+
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //Read data
+    let raw_data = Grid::parse(input, Some)?;
+    end_parse_phase();
+
+    //Get product of gear numbers for gears that have exactly two gear numbers
+    const NUM_REQUIRED_GEAR_NUMBERS: usize = 2;
+    let product_sum = get_gear_numbers(&raw_data)
+        .iter()
+        .filter(|x| x.len() == NUM_REQUIRED_GEAR_NUMBERS)
+        .map(|x| x[0]*x[1])
+        .sum::<u32>();
+    return Ok(Answer::from(product_sum));
+}
+
+//Get valid gear numbers for every gear symbol
+fn get_gear_numbers(raw_data: &Grid<char>) -> Vec<Vec<u32>> {
+    const GEAR_SYMBOL: char = '*';
+    let mut all_gear_numbers = Vec::<Vec<u32>>::new();
+
+    for (gear_pos, _) in raw_data.iter().filter(|(_, x)| **x == GEAR_SYMBOL) {
+        let mut local_gear_numbers = Vec::<u32>::new();
+
+        //Get any adjacent cells that have digits then use that info to extract the actual number. A number
+        //can touch the gear with several digits, so only resolve it from its leftmost adjacent digit.
+        for digit_pos in raw_data.neighbors8(gear_pos).filter(|x| raw_data[*x].is_ascii_digit()) {
+            let left_neighbor_is_digit = digit_pos.1 >= gear_pos.1 && digit_pos.1 > 0 &&
+                raw_data[(digit_pos.0, digit_pos.1 - 1)].is_ascii_digit();
+
+            if !left_neighbor_is_digit {
+                local_gear_numbers.push(resolve_number(raw_data.row(digit_pos.0), digit_pos.1));
+            }
+        }
+
+        all_gear_numbers.push(local_gear_numbers);
+    }
+
+    return all_gear_numbers;
+}
+
+//Assumes starting position is a number
+fn resolve_number(row_data: &[char], mut col_index: usize) -> u32 {
+    //Rewind to start of number
+    while col_index > 0 && row_data[col_index - 1].is_ascii_digit() {
+        col_index -= 1;
+    }
+
+    //Grab and parse number
+    return row_data[col_index..]
+        .iter()
+        .take_while(|x| x.is_ascii_digit())
+        .collect::<String>()
+        .parse::<u32>()
+        .unwrap();
+}
@@ -0,0 +1,58 @@
+#![allow(clippy::needless_return)]
+use std::collections::HashSet;
+use crate::answer::Answer;
+use crate::error::*;
+
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //Read and parse data
+    let total_score = input
+        .lines()
+        .enumerate()
+        .map(|(line_index, line)| parse_game_results(line_index, line))
+        .sum::<Result<u32, SolverError>>()?;
+    return Ok(Answer::from(total_score));
+}
+
+fn parse_game_results(line_index: usize, line: &str) -> Result<u32, SolverError> {
+    const HEADER_DATA_SEPARATOR: char = ':';
+    const WINNING_TEST_NUMBERS_SEPARATOR: char = '|';
+    const NUMBERS_SEPARATOR: char = ' ';
+
+    //Get game data string
+    let Some((_, game_data_str)) = line.split_once(HEADER_DATA_SEPARATOR) else {
+        return Err(SolverError::parse_line(line_index, line, "Missing ':' after the card number"));
+    };
+
+    //Split string into winning numbers and test numbers
+    let Some((winning_numbers_str, test_numbers_str)) = game_data_str.split_once(WINNING_TEST_NUMBERS_SEPARATOR) else {
+        return Err(SolverError::parse_line(line_index, line, "Missing '|' between the number lists"));
+    };
+
+    //Parse strings
+    let winning_numbers = winning_numbers_str
+        .split(NUMBERS_SEPARATOR)
+        .filter(|s| !s.is_empty())
+        .map(|x| parse_token::<u32>(line_index, line, x))
+        .collect::<Result<Vec<u32>, SolverError>>()?;
+
+    let test_numbers = test_numbers_str
+        .split(NUMBERS_SEPARATOR)
+        .filter(|s| !s.is_empty())
+        .map(|x| parse_token::<u32>(line_index, line, x))
+        .collect::<Result<Vec<u32>, SolverError>>()?;
+
+    return Ok(compute_game_score(&(winning_numbers, test_numbers)));
+}
+
+fn compute_game_score(game_data: &(Vec<u32>, Vec<u32>)) -> u32 {
+    const SCORE_BASE: u32 = 2;
+
+    let winning_set = HashSet::<&u32>::from_iter(&game_data.0);
+    let num_matches: u32 =  game_data.1
+        .iter()
+        .filter(|test_num| winning_set.contains(test_num))
+        .count() as u32;
+
+    return if num_matches == 0 { 0 } else { u32::pow(SCORE_BASE, num_matches - 1) };
+}
\ No newline at end of file
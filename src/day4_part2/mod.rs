@@ -0,0 +1,74 @@
+#![allow(clippy::needless_return, clippy::ptr_arg)]
+use std::collections::HashSet;
+use std::convert::TryInto;
+use crate::answer::Answer;
+use crate::error::*;
+
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //Read file, parse data, and compute game scores
+    let game_scores = input
+        .lines()
+        .enumerate()
+        .map(|(line_index, line)| parse_game_results(line_index, line))
+        .collect::<Result<Vec<u32>, SolverError>>()?;
+
+    //Calculate the number of cards
+    return Ok(Answer::from(compute_num_cards(&game_scores)));
+}
+
+fn parse_game_results(line_index: usize, line: &str) -> Result<u32, SolverError> {
+    const HEADER_DATA_SEPARATOR: char = ':';
+    const WINNING_TEST_NUMBERS_SEPARATOR: char = '|';
+    const NUMBERS_SEPARATOR: char = ' ';
+
+    //Get game data string
+    let Some((_, game_data_str)) = line.split_once(HEADER_DATA_SEPARATOR) else {
+        return Err(SolverError::parse_line(line_index, line, "Missing ':' after the card number"));
+    };
+
+    //Split string into winning numbers and test numbers
+    let Some((winning_numbers_str, test_numbers_str)) = game_data_str.split_once(WINNING_TEST_NUMBERS_SEPARATOR) else {
+        return Err(SolverError::parse_line(line_index, line, "Missing '|' between the number lists"));
+    };
+
+    //Parse strings
+    let winning_numbers = winning_numbers_str
+        .split(NUMBERS_SEPARATOR)
+        .filter(|s| !s.is_empty())
+        .map(|x| parse_token::<u32>(line_index, line, x))
+        .collect::<Result<Vec<u32>, SolverError>>()?;
+
+    let test_numbers = test_numbers_str
+        .split(NUMBERS_SEPARATOR)
+        .filter(|s| !s.is_empty())
+        .map(|x| parse_token::<u32>(line_index, line, x))
+        .collect::<Result<Vec<u32>, SolverError>>()?;
+
+    return Ok(compute_game_score(&(winning_numbers, test_numbers)));
+}
+
+fn compute_game_score(game_data: &(Vec<u32>, Vec<u32>)) -> u32 {
+    let winning_set = HashSet::<&u32>::from_iter(&game_data.0);
+    return game_data.1
+        .iter()
+        .filter(|test_num| winning_set.contains(test_num))
+        .count() as u32;
+}
+
+fn compute_num_cards(game_scores: &Vec<u32>) -> u32 {
+    let mut num_cards = vec![1usize; game_scores.len()].into_boxed_slice();
+
+    for num_cards_index in 0..num_cards.len() {
+        for _ in 0..num_cards[num_cards_index] {
+            for relative_index in 1usize..=game_scores[num_cards_index].try_into().unwrap() {
+                let bonus_index = num_cards_index + relative_index;
+                if bonus_index < num_cards.len() {
+                    num_cards[bonus_index] += 1;
+                }
+            }
+        }
+    }
+
+    return num_cards.iter().sum::<usize>() as u32;
+}
\ No newline at end of file
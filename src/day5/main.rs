@@ -0,0 +1,96 @@
+#![allow(clippy::needless_return)]
+use std::env;
+use itertools::Itertools;
+use sandbox::day5::*;
+use sandbox::range_set::RangeSet;
+use sandbox::registry::find_solver;
+use sandbox::runner::*;
+
+const USAGE: &str = "\
+Usage:
+    day5 chain <source> <destination>                 Print the chain of maps between two categories
+    day5 map <source> <destination> <value>...        Map each value from source to destination
+    day5 min <source> <destination> [<start> <length>]...
+                                                      Smallest destination value reached from the given
+                                                      ranges, or from the seed ranges if none are given
+    day5 reverse <source> <destination> <start> <length>...
+                                                      Print every source range that maps into the given
+                                                      destination ranges
+    day5 seeds-below <location>                       Print the listed seed ranges that reach a location
+                                                      below the given one
+
+Options:
+    --input <path>                                    Almanac to read ('-' reads stdin). Defaults to the
+                                                      same input as day5_part1";
+
+//Answers queries against the day 5 almanac, e.g. "day5 map seed humidity 79"
+fn main() {
+    let mut args = env::args().skip(1).collect::<Vec<String>>();
+    let input_argument = take_option(&mut args, "--input", USAGE);
+    let args = args.iter().map(|x| x.as_str()).collect::<Vec<&str>>();
+
+    let solver = find_solver(5, 1).unwrap();
+    let input = read_input(solver, input_argument.as_deref());
+    let almanac = Almanac::parse(&input).unwrap_or_else(|x| exit_with_error(5, &x.to_string()));
+
+    match args.as_slice() {
+        ["chain", source, destination] => {
+            let chain = almanac.find_chain(source, destination).unwrap_or_else(|x| exit_with_error(5, &x.to_string()));
+            println!("{}", chain.join(" -> "));
+        },
+        ["map", source, destination, values @ ..] if !values.is_empty() => {
+            let map = almanac.get_map(source, destination).unwrap_or_else(|x| exit_with_error(5, &x.to_string()));
+            for value in values.iter().map(|x| parse_number(x)) {
+                println!("{} {} -> {} {}", source, value, destination, map.map_value(value));
+            }
+        },
+        ["min", source, destination, ranges @ ..] if ranges.len().is_multiple_of(2) => {
+            let ranges = if ranges.is_empty() {
+                almanac.get_seed_ranges().unwrap_or_else(|x| exit_with_error(5, &x.to_string()))
+            } else {
+                parse_ranges(ranges)
+            };
+
+            let mapped = almanac.map_ranges(source, destination, &ranges).unwrap_or_else(|x| exit_with_error(5, &x.to_string()));
+            match mapped.get_min() {
+                Some(min) => println!("{}", min),
+                None => exit_with_error(5, "The ranges are empty")
+            }
+        },
+        ["reverse", source, destination, ranges @ ..] if !ranges.is_empty() && ranges.len().is_multiple_of(2) => {
+            let source_ranges = almanac.reverse_map_ranges(source, destination, &parse_ranges(ranges)).unwrap_or_else(|x| exit_with_error(5, &x.to_string()));
+            print_ranges(&source_ranges);
+        },
+        ["seeds-below", location] => {
+            let seed_ranges = almanac.get_seed_ranges().unwrap_or_else(|x| exit_with_error(5, &x.to_string()));
+            let low_locations = RangeSet::from(0..parse_number(location));
+            let low_seeds = almanac.reverse_map_ranges(SEED_CATEGORY, LOCATION_CATEGORY, &low_locations).unwrap_or_else(|x| exit_with_error(5, &x.to_string()));
+            print_ranges(&low_seeds.intersection(&seed_ranges));
+        },
+        _ => exit_with_usage("Invalid arguments", USAGE)
+    }
+}
+
+//Reads start/length pairs
+fn parse_ranges(ranges: &[&str]) -> RangeSet {
+    return ranges
+        .iter()
+        .map(|x| parse_number(x))
+        .tuples()
+        .map(|(start, length)| start..start.saturating_add(length))
+        .collect();
+}
+
+//Prints each range as a start/length pair, like the input
+fn print_ranges(ranges: &RangeSet) {
+    for range in ranges.get_ranges() {
+        println!("{} {}", range.start, range.end - range.start);
+    }
+}
+
+fn parse_number(text: &str) -> u64 {
+    return match text.parse::<u64>() {
+        Ok(x) => x,
+        Err(_) => exit_with_usage(&format!("Invalid number '{}'", text), USAGE)
+    };
+}
@@ -0,0 +1,169 @@
+#![allow(clippy::needless_return)]
+use itertools::Itertools;
+use crate::error::*;
+use crate::pathfinding::dijkstra;
+use crate::range_set::{RangeMap, RangeSet};
+
+pub const SEED_CATEGORY: &str = "seed";
+pub const LOCATION_CATEGORY: &str = "location";
+
+//Map between two categories, read from a "<source>-to-<destination> map:" block
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlmanacMap {
+    pub source: String,
+    pub destination: String,
+    pub map: RangeMap
+}
+
+//Seed list and category maps, in the order they appear in the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<AlmanacMap>
+}
+
+impl Almanac {
+    pub fn parse(input: &str) -> Result<Self, SolverError> {
+        let mut line_iter = input.lines().enumerate();
+
+        //Extract seed list
+        let Some((line_index, seed_line)) = line_iter.next() else {
+            return Err(SolverError::invalid_input("Input is empty"));
+        };
+        let seeds = parse_seeds(line_index, seed_line)?;
+
+        //Extract maps. Each header starts a new map and the number lines after it are its entries.
+        let mut maps = Vec::<AlmanacMap>::new();
+        for (line_index, line) in line_iter {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            if let Some(header) = line.trim().strip_suffix(" map:") {
+                let Some((source, destination)) = header.split_once("-to-") else {
+                    return Err(SolverError::parse_line(line_index, line, "Expected a '<source>-to-<destination> map:' header"));
+                };
+                if maps.iter().any(|x| x.source == source && x.destination == destination) {
+                    return Err(SolverError::parse_line(line_index, line, "Duplicate map"));
+                }
+
+                maps.push(AlmanacMap { source: source.to_string(), destination: destination.to_string(), map: RangeMap::new() });
+                continue;
+            }
+
+            let Some(current_map) = maps.last_mut() else {
+                return Err(SolverError::parse_line(line_index, line, "Map entry before the first map header"));
+            };
+            parse_map_entry(line_index, line, &mut current_map.map)?;
+        }
+
+        return Ok(Almanac { seeds, maps });
+    }
+
+    pub fn get_seeds(&self) -> &[u64] {
+        return &self.seeds;
+    }
+
+    //Reads the seed list as start/length pairs
+    pub fn get_seed_ranges(&self) -> Result<RangeSet, SolverError> {
+        if !self.seeds.len().is_multiple_of(2) {
+            return Err(SolverError::invalid_input("Seed ranges must come in start/length pairs"));
+        }
+
+        let mut seed_ranges = RangeSet::new();
+        for (start, length) in self.seeds.iter().tuples() {
+            let Some(end) = start.checked_add(*length) else {
+                return Err(SolverError::invalid_input("Seed range is too large"));
+            };
+            seed_ranges.insert(*start..end);
+        }
+
+        return Ok(seed_ranges);
+    }
+
+    pub fn get_maps(&self) -> &[AlmanacMap] {
+        return &self.maps;
+    }
+
+    //Categories on the shortest chain of maps leading from source to destination, including both ends
+    pub fn find_chain(&self, source: &str, destination: &str) -> Result<Vec<String>, SolverError> {
+        let chain = dijkstra(
+            source.to_string(),
+            |category| self.maps
+                .iter()
+                .filter(|x| x.source == *category)
+                .map(|x| (x.destination.clone(), 1u32))
+                .collect::<Vec<(String, u32)>>(),
+            |category| category == destination
+        );
+
+        return chain
+            .map(|x| x.into_path())
+            .ok_or_else(|| SolverError::invalid_input(&format!("There is no chain of maps from {} to {}", source, destination)));
+    }
+
+    //Composes the maps along the chain from source to destination into a single map
+    pub fn get_map(&self, source: &str, destination: &str) -> Result<RangeMap, SolverError> {
+        let chain = self.find_chain(source, destination)?;
+        return Ok(chain
+            .iter()
+            .tuple_windows()
+            .map(|(from, to)| &self.maps.iter().find(|x| x.source == *from && x.destination == *to).unwrap().map)
+            .fold(RangeMap::new(), |composed, x| composed.compose(x)));
+    }
+
+    pub fn map_value(&self, source: &str, destination: &str, value: u64) -> Result<u64, SolverError> {
+        return Ok(self.get_map(source, destination)?.map_value(value));
+    }
+
+    pub fn map_ranges(&self, source: &str, destination: &str, ranges: &RangeSet) -> Result<RangeSet, SolverError> {
+        return Ok(self.get_map(source, destination)?.map_ranges(ranges));
+    }
+
+    //Every source value that lands in ranges once mapped to destination, e.g. the seeds reaching a set of locations
+    pub fn reverse_map_ranges(&self, source: &str, destination: &str, ranges: &RangeSet) -> Result<RangeSet, SolverError> {
+        return Ok(self.get_map(source, destination)?.preimage(ranges));
+    }
+
+    //Map from destination back to source, if no two source values share a destination value
+    pub fn get_inverse_map(&self, source: &str, destination: &str) -> Result<RangeMap, SolverError> {
+        return self.get_map(source, destination)?
+            .inverse()
+            .ok_or_else(|| SolverError::invalid_input(&format!("The map from {} to {} can't be inverted", source, destination)));
+    }
+}
+
+fn parse_seeds(line_index: usize, line: &str) -> Result<Vec<u64>, SolverError> {
+    let Some((_, seed_list_raw)) = line.split_once(":") else {
+        return Err(SolverError::parse_line(line_index, line, "Missing ':' after 'seeds'"));
+    };
+
+    return seed_list_raw
+        .split(" ")
+        .filter(|s| !s.is_empty())
+        .map(|x| parse_token::<u64>(line_index, line, x))
+        .collect::<Result<Vec<u64>, SolverError>>();
+}
+
+fn parse_map_entry(line_index: usize, line: &str, map: &mut RangeMap) -> Result<(), SolverError> {
+    let map_data = line
+        .split(" ")
+        .filter(|s| !s.is_empty())
+        .map(|x| parse_token::<u64>(line_index, line, x))
+        .collect::<Result<Vec<u64>, SolverError>>()?;
+    if map_data.len() != 3 {
+        return Err(SolverError::parse_line(line_index, line, "Expected three numbers in a map entry"));
+    }
+
+    let value_start = map_data[0];
+    let key_start = map_data[1];
+    let key_length = map_data[2];
+    if key_start.checked_add(key_length).is_none() || value_start.checked_add(key_length).is_none() {
+        return Err(SolverError::parse_line(line_index, line, "Map range is too large"));
+    }
+    if !map.insert(key_start..key_start + key_length, value_start) {
+        return Err(SolverError::parse_line(line_index, line, "Map range overlaps an earlier entry"));
+    }
+
+    return Ok(());
+}
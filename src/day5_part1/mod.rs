@@ -0,0 +1,22 @@
+#![allow(clippy::needless_return)]
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::day5::*;
+use crate::error::SolverError;
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //Parse data
+    let almanac = Almanac::parse(input)?;
+    end_parse_phase();
+
+    //Map over data to get to get min location
+    let seed_location_map = almanac.get_map(SEED_CATEGORY, LOCATION_CATEGORY)?;
+    let min_location = almanac
+        .get_seeds()
+        .iter()
+        .map(|x| seed_location_map.map_value(*x))
+        .min()
+        .ok_or_else(|| SolverError::invalid_input("No seeds were listed"))?;
+
+    return Ok(Answer::from(min_location));
+}
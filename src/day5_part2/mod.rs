@@ -0,0 +1,20 @@
+#![allow(clippy::needless_return)]
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::day5::*;
+use crate::error::SolverError;
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //Parse data
+    let almanac = Almanac::parse(input)?;
+    let seed_ranges = almanac.get_seed_ranges()?;
+    end_parse_phase();
+
+    //The maps along the chain are combined into one so that each seed range only needs mapping once
+    let min_location = almanac
+        .map_ranges(SEED_CATEGORY, LOCATION_CATEGORY, &seed_ranges)?
+        .get_min()
+        .ok_or_else(|| SolverError::invalid_input("No seeds were listed"))?;
+
+    return Ok(Answer::from(min_location));
+}
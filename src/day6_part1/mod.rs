@@ -0,0 +1,53 @@
+#![allow(clippy::needless_return)]
+use std::iter::zip;
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::error::*;
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //Parse data
+    let input_data = parse_data(input)?;
+    end_parse_phase();
+
+    //Calculate product of num ways to win each game
+    let game_win_product = input_data
+        .iter()
+        .map(|x| get_winning_game_count(x.0, x.1))
+        .product::<u64>();
+    return Ok(Answer::from(game_win_product));
+}
+
+fn parse_data(input: &str) -> Result<Vec<(u64, u64)>, SolverError> {
+    let lines = input.lines().collect::<Vec<&str>>();
+    let times = parse_values(&lines, 0)?;
+    let distances = parse_values(&lines, 1)?;
+
+    if times.len() != distances.len() {
+        return Err(SolverError::invalid_input("There must be a distance for every time"));
+    }
+
+    return Ok(zip(times, distances)
+        .collect::<Vec<(u64, u64)>>());
+}
+
+fn parse_values(lines: &Vec<&str>, line_index: usize) -> Result<Vec<u64>, SolverError> {
+    let Some(line) = lines.get(line_index) else {
+        return Err(SolverError::invalid_input("Input must have a time line and a distance line"));
+    };
+    let Some((_, values)) = line.split_once(":") else {
+        return Err(SolverError::parse_line(line_index, line, "Missing ':' after the header"));
+    };
+
+    return values
+        .split(" ")
+        .filter(|s| !s.is_empty())
+        .map(|x| parse_token::<u64>(line_index, line, x))
+        .collect::<Result<Vec<u64>, SolverError>>();
+}
+
+fn get_winning_game_count(max_time: u64, record_distance: u64) -> u64 {
+    return (1..=max_time-1)
+        .map(|x| x * (max_time-x))
+        .filter(|x| *x > record_distance)
+        .count() as u64;
+}
\ No newline at end of file
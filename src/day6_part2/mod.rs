@@ -0,0 +1,44 @@
+#![allow(clippy::needless_return)]
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::error::*;
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //Parse data
+    let input_data = parse_data(input)?;
+    end_parse_phase();
+    return Ok(Answer::from(get_winning_game_count(input_data.0, input_data.1)));
+}
+
+fn parse_data(input: &str) -> Result<(u64, u64), SolverError> {
+    let lines = input.lines().collect::<Vec<&str>>();
+    let time = parse_value(&lines, 0)?;
+    let distance = parse_value(&lines, 1)?;
+
+    return Ok((time, distance));
+}
+
+//The spaces between the digits are ignored since they are all one number
+fn parse_value(lines: &Vec<&str>, line_index: usize) -> Result<u64, SolverError> {
+    let Some(line) = lines.get(line_index) else {
+        return Err(SolverError::invalid_input("Input must have a time line and a distance line"));
+    };
+    let Some((_, value)) = line.split_once(":") else {
+        return Err(SolverError::parse_line(line_index, line, "Missing ':' after the header"));
+    };
+
+    return value
+        .split(" ")
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<&str>>()
+        .join("")
+        .parse::<u64>()
+        .map_err(|_| SolverError::parse_token(line_index, line, value.trim(), "Invalid number"));
+}
+
+fn get_winning_game_count(max_time: u64, record_distance: u64) -> u64 {
+    return (1..=max_time-1)
+        .map(|x| x * (max_time-x))
+        .filter(|x| *x > record_distance)
+        .count() as u64;
+}
\ No newline at end of file
@@ -0,0 +1,162 @@
+#![allow(clippy::needless_return)]
+use std::cmp::Ordering;
+use crate::day7::hand::HandType::*;
+use crate::day7::rules::RuleSet;
+
+#[derive(PartialOrd, PartialEq, Ord, Eq, Copy, Clone, Default, Hash, Debug)]
+pub enum Card
+{
+    #[default]
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace
+}
+
+impl Card {
+    pub const ALL: [Card; 13] = [Card::Two, Card::Three, Card::Four, Card::Five, Card::Six, Card::Seven, Card::Eight,
+        Card::Nine, Card::Ten, Card::Jack, Card::Queen, Card::King, Card::Ace];
+
+    pub fn from_label(label: char) -> Option<Self> {
+        return match label {
+            '2' => Some(Card::Two),
+            '3' => Some(Card::Three),
+            '4' => Some(Card::Four),
+            '5' => Some(Card::Five),
+            '6' => Some(Card::Six),
+            '7' => Some(Card::Seven),
+            '8' => Some(Card::Eight),
+            '9' => Some(Card::Nine),
+            'T' => Some(Card::Ten),
+            'J' => Some(Card::Jack),
+            'Q' => Some(Card::Queen),
+            'K' => Some(Card::King),
+            'A' => Some(Card::Ace),
+            _ => None
+        };
+    }
+
+    pub fn get_label(&self) -> char {
+        return match self {
+            Card::Two => '2',
+            Card::Three => '3',
+            Card::Four => '4',
+            Card::Five => '5',
+            Card::Six => '6',
+            Card::Seven => '7',
+            Card::Eight => '8',
+            Card::Nine => '9',
+            Card::Ten => 'T',
+            Card::Jack => 'J',
+            Card::Queen => 'Q',
+            Card::King => 'K',
+            Card::Ace => 'A'
+        };
+    }
+}
+
+#[derive(PartialOrd, PartialEq, Ord, Eq, Copy, Clone, Default, Hash, Debug)]
+pub enum HandType {
+    #[default]
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind
+}
+
+//Hand scored under a rule set. The type and card strengths are worked out up front, so hands scored under
+//different rule sets shouldn't be compared with each other.
+#[derive(Debug, Clone)]
+pub struct Hand {
+    cards: [Card; 5],
+    card_strengths: [u8; 5],
+    hand_type: HandType,
+    bid: u32
+}
+
+impl Hand {
+    pub fn get_cards(&self) -> &[Card; 5] {
+        return &self.cards;
+    }
+
+    pub fn get_hand_type(&self) -> &HandType {
+        return &self.hand_type;
+    }
+
+    pub fn get_bid(&self) -> &u32 {
+        return &self.bid;
+    }
+
+    pub fn new(cards: [Card; 5], bid: u32, rules: &dyn RuleSet) -> Self {
+        Self {
+            cards,
+            card_strengths: cards.map(|x| rules.get_card_strength(x)),
+            hand_type: Hand::calculate_hand_type(&cards, rules),
+            bid
+        }
+    }
+
+    //Works for any number of cards. Wild cards all join the largest group of matching cards since that always
+    //gives the strongest type.
+    pub fn calculate_hand_type(cards: &[Card], rules: &dyn RuleSet) -> HandType {
+        let mut card_counts = [0usize; Card::ALL.len()];
+        let mut num_wild_cards = 0usize;
+        for card in cards {
+            if rules.is_wild(*card) {
+                num_wild_cards += 1;
+            } else {
+                card_counts[*card as usize] += 1;
+            }
+        }
+
+        card_counts.sort_unstable_by(|a, b| b.cmp(a));
+        return Self::classify_group_sizes(card_counts[0] + num_wild_cards, card_counts[1]);
+    }
+
+    //Type from the sizes of the two largest groups of matching cards
+    fn classify_group_sizes(largest: usize, second_largest: usize) -> HandType {
+        return match (largest, second_largest) {
+            (5.., _) => FiveOfAKind,
+            (4, _) => FourOfAKind,
+            (3, 2..) => FullHouse,
+            (3, _) => ThreeOfAKind,
+            (2, 2..) => TwoPair,
+            (2, _) => OnePair,
+            _ => HighCard
+        };
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        return self.cmp(other) == Ordering::Equal;
+    }
+}
+
+impl Eq for Hand {}
+
+//Hands are ranked by type, then card by card from the first card using the rule set's card strengths
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        return self.hand_type.cmp(&other.hand_type)
+            .then_with(|| self.card_strengths.cmp(&other.card_strengths));
+    }
+}
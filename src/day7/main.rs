@@ -0,0 +1,71 @@
+#![allow(clippy::needless_return)]
+use std::env;
+use sandbox::day7::*;
+use sandbox::day7::report::HandReport;
+use sandbox::day7::rules::*;
+use sandbox::registry::find_solver;
+use sandbox::runner::*;
+
+const USAGE: &str = "\
+Usage: day7 [input path | -] [options]
+
+Options:
+    --rules standard|jokers     Score with the part 1 or part 2 rules (default standard)
+    --order <labels>            Custom card order, weakest first, e.g. J23456789TQKA
+    --wild <labels>             Custom wild cards, e.g. J. Uses the standard order unless --order is given
+    --report                    Print hand type counts, wild card upgrades, bids and the strongest hands
+    --top <count>               Number of strongest hands in the report (default 5)
+
+Input is read the same way as for day7_part1.";
+
+//Runs day 7 with any rule set
+fn main() {
+    let mut args = env::args().skip(1).collect::<Vec<String>>();
+    let rules = take_rules(&mut args);
+    let top_count = take_option(&mut args, "--top", USAGE).map(|x| match x.parse::<usize>() {
+        Ok(count) => count,
+        Err(_) => exit_with_usage(&format!("Invalid count '{}'", x), USAGE)
+    });
+    let report = take_flag(&mut args, "--report");
+    if top_count.is_some() && !report {
+        exit_with_usage("--top only applies to --report", USAGE);
+    }
+
+    let solver = find_solver(7, 1).unwrap();
+    let input = read_positional_input(solver, &args).unwrap_or_else(|x| exit_with_usage(&x, USAGE));
+
+    if report {
+        let mut hands = parse_data(&input, rules.as_ref()).unwrap_or_else(|x| exit_with_error(7, &x.to_string()));
+        hands.sort();
+        print!("{}", HandReport::new(&hands, top_count.unwrap_or(5)));
+        return;
+    }
+
+    match solve_with_rules(&input, rules.as_ref()) {
+        Ok(answer) => println!("The total winnings are {}", answer),
+        Err(error) => exit_with_error(7, &error.to_string())
+    }
+}
+
+fn take_rules(args: &mut Vec<String>) -> Box<dyn RuleSet> {
+    let preset = take_option(args, "--rules", USAGE);
+    let card_order = take_option(args, "--order", USAGE);
+    let wild_cards = take_option(args, "--wild", USAGE);
+
+    if card_order.is_none() && wild_cards.is_none() {
+        return match preset.as_deref() {
+            None | Some("standard") => Box::new(StandardRules),
+            Some("jokers") => Box::new(JokerRules),
+            Some(other) => exit_with_usage(&format!("Unknown rule set '{}'", other), USAGE)
+        };
+    }
+
+    if preset.is_some() {
+        exit_with_usage("--rules can't be combined with --order or --wild", USAGE);
+    }
+    let card_order = card_order.unwrap_or_else(|| String::from("23456789TJQKA"));
+    return match CustomRules::parse(&card_order, &wild_cards.unwrap_or_default()) {
+        Ok(rules) => Box::new(rules),
+        Err(message) => exit_with_usage(&message, USAGE)
+    };
+}
@@ -0,0 +1,58 @@
+#![allow(clippy::needless_return)]
+use crate::day7::hand::*;
+use crate::day7::rules::RuleSet;
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::error::*;
+
+pub mod hand;
+pub mod report;
+pub mod rules;
+
+pub fn solve_with_rules(input: &str, rules: &dyn RuleSet) -> Result<Answer, SolverError> {
+    //Parse data
+    let mut input = parse_data(input, rules)?;
+    end_parse_phase();
+
+    //Calculate final result
+    input.sort();
+    return Ok(Answer::from(get_total_winnings(&input)));
+}
+
+//Hands must be sorted weakest first so that each one's rank is its position plus one
+pub fn get_total_winnings(sorted_hands: &[Hand]) -> u64 {
+    return sorted_hands
+        .iter()
+        .enumerate()
+        .map(|x| (x.0 as u64 + 1) * *x.1.get_bid() as u64)
+        .sum::<u64>();
+}
+
+pub fn parse_data(input: &str, rules: &dyn RuleSet) -> Result<Vec<Hand>, SolverError> {
+    return input
+        .lines()
+        .enumerate()
+        .map(|(line_index, l)| parse_hand_data(line_index, l, rules))
+        .collect::<Result<Vec<Hand>, SolverError>>();
+}
+
+fn parse_hand_data(line_index: usize, line: &str, rules: &dyn RuleSet) -> Result<Hand, SolverError> {
+    let Some((cards_raw, bid_raw)) = line.split_once(" ") else {
+        return Err(SolverError::parse_line(line_index, line, "Expected a hand followed by a bid"));
+    };
+    let bid = parse_token::<u32>(line_index, line, bid_raw)?;
+
+    let mut cards: [Card; 5] = Default::default();
+    if cards_raw.chars().count() != cards.len() {
+        return Err(SolverError::parse_token(line_index, line, cards_raw, "A hand must have five cards"));
+    }
+
+    for (index, card_char) in cards_raw.chars().enumerate() {
+        let Some(card) = Card::from_label(card_char) else {
+            return Err(SolverError::parse(line_index, index, &card_char.to_string(), "Invalid card"));
+        };
+        cards[index] = card;
+    }
+
+    return Ok(Hand::new(cards, bid, rules));
+}
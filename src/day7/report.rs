@@ -0,0 +1,186 @@
+#![allow(clippy::needless_return)]
+use std::collections::BTreeMap;
+use std::fmt;
+use crate::day7::hand::*;
+use crate::day7::rules::StandardRules;
+
+//Rank, bid and winnings of all the hands of one type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeSummary {
+    count: usize,
+    lowest_rank: usize,
+    highest_rank: usize,
+    total_bid: u64,
+    winnings: u64
+}
+
+impl TypeSummary {
+    pub fn get_count(&self) -> usize {
+        return self.count;
+    }
+
+    pub fn get_lowest_rank(&self) -> usize {
+        return self.lowest_rank;
+    }
+
+    pub fn get_highest_rank(&self) -> usize {
+        return self.highest_rank;
+    }
+
+    pub fn get_total_bid(&self) -> u64 {
+        return self.total_bid;
+    }
+
+    pub fn get_winnings(&self) -> u64 {
+        return self.winnings;
+    }
+}
+
+//Hand along with its rank among all the hands it was scored against
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RankedHand {
+    rank: usize,
+    hand: Hand
+}
+
+impl RankedHand {
+    pub fn get_rank(&self) -> usize {
+        return self.rank;
+    }
+
+    pub fn get_hand(&self) -> &Hand {
+        return &self.hand;
+    }
+
+    pub fn get_winnings(&self) -> u64 {
+        return self.rank as u64 * *self.hand.get_bid() as u64;
+    }
+}
+
+//Statistics about a set of scored hands, used to sanity check inputs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandReport {
+    hand_count: usize,
+    type_summaries: BTreeMap<HandType, TypeSummary>,
+    //Keyed by the type without wild cards and the type the wild cards turned it into
+    upgrades: BTreeMap<(HandType, HandType), usize>,
+    //Bids sorted from lowest to highest
+    sorted_bids: Vec<u32>,
+    //Strongest first
+    top_hands: Vec<RankedHand>
+}
+
+impl HandReport {
+    //Hands must be sorted weakest first, as for get_total_winnings
+    pub fn new(sorted_hands: &[Hand], top_count: usize) -> Self {
+        let mut type_summaries = BTreeMap::<HandType, TypeSummary>::new();
+        let mut upgrades = BTreeMap::<(HandType, HandType), usize>::new();
+        for (index, hand) in sorted_hands.iter().enumerate() {
+            let rank = index + 1;
+            let bid = *hand.get_bid() as u64;
+            let summary = type_summaries.entry(*hand.get_hand_type()).or_insert(TypeSummary {
+                count: 0,
+                lowest_rank: rank,
+                highest_rank: rank,
+                total_bid: 0,
+                winnings: 0
+            });
+            summary.count += 1;
+            summary.highest_rank = rank;
+            summary.total_bid += bid;
+            summary.winnings += rank as u64 * bid;
+
+            let natural_type = Hand::calculate_hand_type(hand.get_cards(), &StandardRules);
+            if natural_type != *hand.get_hand_type() {
+                *upgrades.entry((natural_type, *hand.get_hand_type())).or_insert(0) += 1;
+            }
+        }
+
+        let mut sorted_bids = sorted_hands.iter().map(|x| *x.get_bid()).collect::<Vec<u32>>();
+        sorted_bids.sort_unstable();
+
+        let top_hands = sorted_hands
+            .iter()
+            .enumerate()
+            .rev()
+            .take(top_count)
+            .map(|(index, hand)| RankedHand { rank: index + 1, hand: hand.clone() })
+            .collect::<Vec<RankedHand>>();
+
+        return HandReport { hand_count: sorted_hands.len(), type_summaries, upgrades, sorted_bids, top_hands };
+    }
+
+    pub fn get_hand_count(&self) -> usize {
+        return self.hand_count;
+    }
+
+    pub fn get_type_summary(&self, hand_type: HandType) -> Option<&TypeSummary> {
+        return self.type_summaries.get(&hand_type);
+    }
+
+    pub fn get_upgrades(&self) -> &BTreeMap<(HandType, HandType), usize> {
+        return &self.upgrades;
+    }
+
+    pub fn get_upgrade_count(&self) -> usize {
+        return self.upgrades.values().sum::<usize>();
+    }
+
+    pub fn get_min_bid(&self) -> Option<u32> {
+        return self.sorted_bids.first().copied();
+    }
+
+    pub fn get_max_bid(&self) -> Option<u32> {
+        return self.sorted_bids.last().copied();
+    }
+
+    //Lower median when there's an even number of bids
+    pub fn get_median_bid(&self) -> Option<u32> {
+        if self.sorted_bids.is_empty() {
+            return None;
+        }
+        return Some(self.sorted_bids[(self.sorted_bids.len() - 1) / 2]);
+    }
+
+    pub fn get_total_winnings(&self) -> u64 {
+        return self.type_summaries.values().map(|x| x.winnings).sum::<u64>();
+    }
+
+    pub fn get_top_hands(&self) -> &[RankedHand] {
+        return &self.top_hands;
+    }
+}
+
+fn get_labels(hand: &Hand) -> String {
+    return hand.get_cards().iter().map(|x| x.get_label()).collect::<String>();
+}
+
+impl fmt::Display for HandReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} hands, total winnings {}", self.hand_count, self.get_total_winnings())?;
+
+        writeln!(f, "\n{:<14}{:>7}{:>10}{:>10}{:>11}{:>14}", "Type", "Count", "Min rank", "Max rank", "Total bid", "Winnings")?;
+        for (hand_type, summary) in self.type_summaries.iter().rev() {
+            writeln!(f, "{:<14}{:>7}{:>10}{:>10}{:>11}{:>14}", format!("{:?}", hand_type), summary.count,
+                summary.lowest_rank, summary.highest_rank, summary.total_bid, summary.winnings)?;
+        }
+
+        writeln!(f, "\n{} hands upgraded by wild cards", self.get_upgrade_count())?;
+        for ((from, to), count) in self.upgrades.iter() {
+            writeln!(f, "    {:?} -> {:?}: {}", from, to, count)?;
+        }
+
+        if let (Some(min), Some(median), Some(max)) = (self.get_min_bid(), self.get_median_bid(), self.get_max_bid()) {
+            writeln!(f, "\nBids: min {}, median {}, max {}", min, median, max)?;
+        }
+
+        if !self.top_hands.is_empty() {
+            writeln!(f, "\nTop {} hands", self.top_hands.len())?;
+        }
+        for ranked in self.top_hands.iter() {
+            writeln!(f, "    #{} {} {:?} bid {} wins {}", ranked.rank, get_labels(&ranked.hand),
+                ranked.hand.get_hand_type(), ranked.hand.get_bid(), ranked.get_winnings())?;
+        }
+        return Ok(());
+    }
+}
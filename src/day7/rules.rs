@@ -0,0 +1,75 @@
+#![allow(clippy::needless_return)]
+use crate::day7::hand::Card;
+
+//How cards are valued when scoring hands
+pub trait RuleSet {
+    //Strength used to break ties between hands of the same type, higher is stronger
+    fn get_card_strength(&self, card: Card) -> u8;
+
+    //Wild cards act as whichever card makes the strongest hand type
+    fn is_wild(&self, card: Card) -> bool;
+}
+
+//Part 1 rules: no wild cards, aces high
+pub struct StandardRules;
+
+impl RuleSet for StandardRules {
+    fn get_card_strength(&self, card: Card) -> u8 {
+        return card as u8;
+    }
+
+    fn is_wild(&self, _card: Card) -> bool {
+        return false;
+    }
+}
+
+//Part 2 rules: J is a wild joker and the weakest card when breaking ties
+pub struct JokerRules;
+
+impl RuleSet for JokerRules {
+    fn get_card_strength(&self, card: Card) -> u8 {
+        return if card == Card::Jack { 0 } else { card as u8 + 1 };
+    }
+
+    fn is_wild(&self, card: Card) -> bool {
+        return card == Card::Jack;
+    }
+}
+
+//Any ordering of the cards, with any of them wild
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomRules {
+    //Weakest card first
+    card_order: Vec<Card>,
+    wild_cards: Vec<Card>
+}
+
+impl CustomRules {
+    //Builds the rules from card labels, e.g. "J23456789TQKA" and "J" for the part 2 rules
+    pub fn parse(card_order: &str, wild_cards: &str) -> Result<Self, String> {
+        let parse_labels = |labels: &str| labels
+            .chars()
+            .map(|x| Card::from_label(x).ok_or_else(|| format!("Invalid card '{}'", x)))
+            .collect::<Result<Vec<Card>, String>>();
+
+        let card_order = parse_labels(card_order)?;
+        if let Some(missing) = Card::ALL.iter().find(|x| !card_order.contains(x)) {
+            return Err(format!("The card order is missing '{}'", missing.get_label()));
+        }
+        if card_order.len() != Card::ALL.len() {
+            return Err(String::from("The card order lists a card more than once"));
+        }
+
+        return Ok(CustomRules { card_order, wild_cards: parse_labels(wild_cards)? });
+    }
+}
+
+impl RuleSet for CustomRules {
+    fn get_card_strength(&self, card: Card) -> u8 {
+        return self.card_order.iter().position(|x| *x == card).unwrap() as u8;
+    }
+
+    fn is_wild(&self, card: Card) -> bool {
+        return self.wild_cards.contains(&card);
+    }
+}
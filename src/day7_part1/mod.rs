@@ -0,0 +1,9 @@
+#![allow(clippy::needless_return)]
+use crate::answer::Answer;
+use crate::day7::rules::StandardRules;
+use crate::day7::solve_with_rules;
+use crate::error::SolverError;
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    return solve_with_rules(input, &StandardRules);
+}
@@ -0,0 +1,168 @@
+#![allow(clippy::needless_return)]
+use std::collections::VecDeque;
+use std::fmt::Write;
+use serde::Serialize;
+use crate::day8::*;
+
+#[derive(Serialize)]
+struct NetworkJson<'a> {
+    instructions: &'a str,
+    nodes: Vec<NodeJson<'a>>
+}
+
+#[derive(Serialize)]
+struct NodeJson<'a> {
+    name: &'a str,
+    left: &'a str,
+    right: &'a str,
+    start: bool,
+    end: bool
+}
+
+//Exports and analysis of the network as a directed graph with an edge for each step
+impl DesertNetwork {
+    //Graphviz digraph with an edge per step, start nodes in green and end nodes in red
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph desert {\n");
+        for name in self.names.iter() {
+            if is_start_node(name) {
+                writeln!(dot, "    \"{}\" [style=filled, fillcolor=palegreen];", name).unwrap();
+            } else if is_end_node(name) {
+                writeln!(dot, "    \"{}\" [style=filled, fillcolor=salmon];", name).unwrap();
+            }
+        }
+
+        for (name, [left, right]) in self.names.iter().zip(self.transitions.iter()) {
+            if left == right {
+                writeln!(dot, "    \"{}\" -> \"{}\" [label=\"L,R\"];", name, self.get_name(*left)).unwrap();
+            } else {
+                writeln!(dot, "    \"{}\" -> \"{}\" [label=\"L\"];", name, self.get_name(*left)).unwrap();
+                writeln!(dot, "    \"{}\" -> \"{}\" [label=\"R\"];", name, self.get_name(*right)).unwrap();
+            }
+        }
+
+        dot.push_str("}\n");
+        return dot;
+    }
+
+    pub fn to_json(&self) -> String {
+        let nodes = self.names
+            .iter()
+            .zip(self.transitions.iter())
+            .map(|(name, [left, right])| NodeJson {
+                name,
+                left: self.get_name(*left),
+                right: self.get_name(*right),
+                start: is_start_node(name),
+                end: is_end_node(name)
+            })
+            .collect::<Vec<NodeJson>>();
+
+        return serde_json::to_string_pretty(&NetworkJson { instructions: &self.instructions, nodes }).unwrap();
+    }
+
+    //Tarjan's algorithm, using an explicit stack so large generated networks can't overflow the call stack.
+    //Components are listed largest first, each with its nodes in input order.
+    pub fn find_strongly_connected_components(&self) -> Vec<Vec<NodeId>> {
+        let node_count = self.names.len();
+        let mut visit_order = vec![None; node_count];
+        let mut lowest_reachable = vec![0usize; node_count];
+        let mut on_stack = vec![false; node_count];
+        let mut component_stack = Vec::<usize>::new();
+        let mut components = Vec::<Vec<NodeId>>::new();
+        let mut num_visited = 0usize;
+
+        for root in 0..node_count {
+            if visit_order[root].is_some() {
+                continue;
+            }
+
+            //Each entry is a node and how many of its successors have been looked at
+            let mut call_stack = vec![(root, 0usize)];
+            visit_order[root] = Some(num_visited);
+            lowest_reachable[root] = num_visited;
+            num_visited += 1;
+            component_stack.push(root);
+            on_stack[root] = true;
+
+            while let Some((node, num_explored)) = call_stack.last_mut() {
+                let node = *node;
+                if let Some(&successor) = self.transitions[node].get(*num_explored) {
+                    let successor = successor as usize;
+                    *num_explored += 1;
+                    match visit_order[successor] {
+                        None => {
+                            visit_order[successor] = Some(num_visited);
+                            lowest_reachable[successor] = num_visited;
+                            num_visited += 1;
+                            component_stack.push(successor);
+                            on_stack[successor] = true;
+                            call_stack.push((successor, 0));
+                        },
+                        Some(order) if on_stack[successor] => {
+                            lowest_reachable[node] = lowest_reachable[node].min(order);
+                        },
+                        Some(_) => {}
+                    }
+                    continue;
+                }
+
+                call_stack.pop();
+                if let Some((parent, _)) = call_stack.last() {
+                    lowest_reachable[*parent] = lowest_reachable[*parent].min(lowest_reachable[node]);
+                }
+
+                if Some(lowest_reachable[node]) == visit_order[node] {
+                    let mut component = Vec::<NodeId>::new();
+                    loop {
+                        let member = component_stack.pop().unwrap();
+                        on_stack[member] = false;
+                        component.push(member as NodeId);
+                        if member == node {
+                            break;
+                        }
+                    }
+                    component.sort();
+                    components.push(component);
+                }
+            }
+        }
+
+        components.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        return components;
+    }
+
+    //End nodes reachable from each start node when any step can be taken, ignoring the instructions
+    pub fn find_reachable_ends(&self) -> Vec<(NodeId, Vec<NodeId>)> {
+        return self
+            .get_start_nodes()
+            .into_iter()
+            .map(|start| {
+                let mut visited = vec![false; self.names.len()];
+                let mut queue = VecDeque::from([start]);
+                visited[start as usize] = true;
+                while let Some(node) = queue.pop_front() {
+                    for successor in self.get_successors(node) {
+                        if !visited[successor as usize] {
+                            visited[successor as usize] = true;
+                            queue.push_back(successor);
+                        }
+                    }
+                }
+
+                let ends = (0..self.names.len() as NodeId)
+                    .filter(|x| visited[*x as usize] && self.is_end(*x))
+                    .collect::<Vec<NodeId>>();
+                (start, ends)
+            })
+            .collect();
+    }
+
+    //A component is a cycle in the network if it has more than one node or a node that steps to itself
+    pub fn is_cyclic(&self, component: &[NodeId]) -> bool {
+        return match component {
+            [node] => self.get_successors(*node).contains(node),
+            _ => component.len() > 1
+        };
+    }
+}
@@ -0,0 +1,54 @@
+#![allow(clippy::needless_return)]
+use crate::day8::*;
+
+//Where each node ends up after 2^k full passes of the instructions. Any walk then takes one lookup per set bit
+//of its number of passes plus less than one pass of single steps.
+pub struct JumpTable<'a> {
+    network: &'a DesertNetwork,
+    //passes[k][node] is the node reached after 2^k passes
+    passes: Vec<Vec<NodeId>>
+}
+
+impl<'a> JumpTable<'a> {
+    //Builds enough levels for walks of up to max_steps steps
+    pub fn new(network: &'a DesertNetwork, max_steps: u64) -> Self {
+        let instruction_count = network.get_instructions().len() as u64;
+        let one_pass = (0..network.get_node_count() as NodeId)
+            .map(|x| network.walk(x, instruction_count))
+            .collect::<Vec<NodeId>>();
+
+        let max_passes = max_steps / instruction_count;
+        let mut passes = vec![one_pass];
+        while passes.len() < u64::BITS as usize && max_passes >> passes.len() > 0 {
+            let previous = passes.last().unwrap();
+            let doubled = previous.iter().map(|x| previous[*x as usize]).collect::<Vec<NodeId>>();
+            passes.push(doubled);
+        }
+
+        return JumpTable { network, passes };
+    }
+
+    //Largest walk the table has levels for
+    pub fn get_max_steps(&self) -> u64 {
+        let instruction_count = self.network.get_instructions().len() as u64;
+        let max_passes = u64::MAX >> (u64::BITS - self.passes.len() as u32);
+        return max_passes.saturating_mul(instruction_count).saturating_add(instruction_count - 1);
+    }
+
+    //Panics if the walk is longer than the table was built for
+    pub fn walk(&self, start: NodeId, num_steps: u64) -> NodeId {
+        assert!(num_steps <= self.get_max_steps(), "The jump table only covers {} steps", self.get_max_steps());
+        let instruction_count = self.network.get_instructions().len() as u64;
+        let num_passes = num_steps / instruction_count;
+
+        let mut node = start;
+        for (level, pass) in self.passes.iter().enumerate() {
+            if (num_passes >> level) & 1 == 1 {
+                node = pass[node as usize];
+            }
+        }
+
+        //Whole passes end back on the first instruction
+        return self.network.walk(node, num_steps % instruction_count);
+    }
+}
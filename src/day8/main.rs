@@ -0,0 +1,77 @@
+use std::env;
+use itertools::Itertools;
+use sandbox::day8::*;
+use sandbox::day8::jump_table::JumpTable;
+use sandbox::day8_part2::scalar_solver_iterator::ScalarSolverIterator;
+use sandbox::registry::find_solver;
+use sandbox::runner::*;
+
+const USAGE: &str = "\
+Usage:
+    day8 dot              Print the network as a Graphviz digraph
+    day8 json             Print the instructions and network as JSON
+    day8 scc              Print the strongly connected components that contain a cycle
+    day8 reach            Print the end nodes each start node can reach, ignoring the instructions
+    day8 cycles           Print where each start node's walk under the instructions starts repeating,
+                          and the steps on which it stands on an end node
+    day8 walk <node> <steps>...
+                          Print the node reached after each number of steps from the given node
+
+Options:
+    --input <path>        Network to read ('-' reads stdin). Defaults to the same input as day8_part1";
+
+//Exports and analyses the day 8 desert network, e.g. "day8 dot | dot -Tsvg > network.svg"
+fn main() {
+    let mut args = env::args().skip(1).collect::<Vec<String>>();
+    let input_argument = take_option(&mut args, "--input", USAGE);
+    let args = args.iter().map(|x| x.as_str()).collect::<Vec<&str>>();
+
+    let solver = find_solver(8, 1).unwrap();
+    let input = read_input(solver, input_argument.as_deref());
+    let network = DesertNetwork::parse(&input).unwrap_or_else(|x| exit_with_error(8, &x.to_string()));
+    let names = |nodes: &[NodeId]| nodes.iter().map(|x| network.get_name(*x)).join(" ");
+
+    match args.as_slice() {
+        ["dot"] => print!("{}", network.to_dot()),
+        ["json"] => println!("{}", network.to_json()),
+        ["scc"] => {
+            let components = network.find_strongly_connected_components();
+            let cyclic = components.iter().filter(|x| network.is_cyclic(x)).collect::<Vec<&Vec<NodeId>>>();
+            println!("{} nodes, {} components, {} with a cycle", network.get_node_count(), components.len(), cyclic.len());
+            for component in cyclic {
+                println!("{}: {}", component.len(), names(component));
+            }
+        },
+        ["reach"] => {
+            for (start, ends) in network.find_reachable_ends() {
+                println!("{} -> {}", network.get_name(start), if ends.is_empty() { String::from("(none)") } else { names(&ends) });
+            }
+        },
+        ["cycles"] => {
+            for start in network.get_start_nodes() {
+                let cycle = ScalarSolverIterator::new(start, &network).detect_cycle();
+                println!("{}: prefix {} steps, cycle {} steps ({} passes of the instructions){}", network.get_name(start),
+                    cycle.get_prefix_length(), cycle.get_cycle_length(),
+                    cycle.get_cycle_length() / network.get_instructions().len() as u64,
+                    if cycle.is_aligned() { ", aligned" } else { "" });
+                println!("    hits before the cycle: [{}], hits in the cycle: [{}]",
+                    cycle.get_prefix_hits().iter().join(", "), cycle.get_cycle_hits().iter().join(", "));
+            }
+        },
+        ["walk", start, step_counts @ ..] if !step_counts.is_empty() => {
+            let Some(start_node) = network.get_id(start) else {
+                exit_with_error(8, &format!("Unknown node '{}'", start));
+            };
+            let step_counts = step_counts
+                .iter()
+                .map(|x| x.parse::<u64>().unwrap_or_else(|_| exit_with_usage(&format!("Invalid number of steps '{}'", x), USAGE)))
+                .collect::<Vec<u64>>();
+
+            let jump_table = JumpTable::new(&network, *step_counts.iter().max().unwrap());
+            for num_steps in step_counts {
+                println!("{} + {} steps -> {}", start, num_steps, network.get_name(jump_table.walk(start_node, num_steps)));
+            }
+        },
+        _ => exit_with_usage("Invalid arguments", USAGE)
+    }
+}
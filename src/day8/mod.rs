@@ -0,0 +1,144 @@
+#![allow(clippy::needless_return)]
+pub mod graph;
+pub mod jump_table;
+
+use std::collections::HashMap;
+use crate::error::SolverError;
+
+//Dense index of a node, in the order the nodes are listed in the input
+pub type NodeId = u32;
+
+//Ghosts start on every node ending in A and finish on every node ending in Z
+pub fn is_start_node(node: &str) -> bool {
+    return node.ends_with("A");
+}
+
+pub fn is_end_node(node: &str) -> bool {
+    return node.ends_with("Z");
+}
+
+//Instructions and network with the node names interned, so a step is a lookup in a flat transition table
+#[derive(Debug, Clone)]
+pub struct DesertNetwork {
+    instructions: String,
+    //0 for left and 1 for right, to index the transition table with
+    turns: Vec<usize>,
+    names: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    //Left then right successor of each node
+    transitions: Vec<[NodeId; 2]>,
+    end_nodes: Vec<bool>
+}
+
+impl DesertNetwork {
+    pub fn parse(input: &str) -> Result<Self, SolverError> {
+        let mut lines_iter = input
+            .lines()
+            .map(String::from)
+            .enumerate();
+
+        let Some((line_index, instructions)) = lines_iter.next() else {
+            return Err(SolverError::invalid_input("Input is empty"));
+        };
+        if instructions.is_empty() {
+            return Err(SolverError::parse_line(line_index, &instructions, "There are no instructions"));
+        }
+        if let Some(column_index) = instructions.find(|x| x != 'L' && x != 'R') {
+            return Err(SolverError::parse_char(line_index, &instructions, column_index, "Invalid instruction"));
+        }
+
+        lines_iter.next(); //consume blank line
+        let mut network = DesertNetwork {
+            turns: instructions.bytes().map(|x| if x == b'L' { 0 } else { 1 }).collect(),
+            instructions,
+            names: Vec::<String>::new(),
+            ids: HashMap::<String, NodeId>::new(),
+            transitions: Vec::<[NodeId; 2]>::new(),
+            end_nodes: Vec::<bool>::new()
+        };
+        network.parse_map_data(&mut lines_iter)?;
+
+        return Ok(network);
+    }
+
+    fn parse_map_data(&mut self, lines_iter: &mut impl Iterator<Item=(usize, String)>) -> Result<(), SolverError> {
+        let mut node_lines = Vec::<(usize, String)>::new();
+
+        //Number the nodes first so steps can point forward to nodes listed later
+        for (line_index, line) in lines_iter.by_ref() {
+            let Some((node_code, _)) = line.split_once("=") else {
+                return Err(SolverError::parse_line(line_index, &line, "Expected a node followed by '='"));
+            };
+
+            let node_code = node_code.trim().to_string();
+            if self.ids.contains_key(&node_code) {
+                return Err(SolverError::parse_token(line_index, &line, &node_code, "Duplicate node"));
+            }
+            self.ids.insert(node_code.clone(), self.names.len() as NodeId);
+            self.end_nodes.push(is_end_node(&node_code));
+            self.names.push(node_code);
+            node_lines.push((line_index, line));
+        }
+
+        //Every step has to lead to a node in the map so the walk can't get stuck
+        for (line_index, line) in node_lines {
+            let steps = line.split_once("=").unwrap().1.trim();
+            let Some((left_step, right_step)) = steps
+                .strip_prefix("(")
+                .and_then(|x| x.strip_suffix(")"))
+                .and_then(|x| x.split_once(",")) else {
+                return Err(SolverError::parse_token(line_index, &line, steps, "Expected steps in the form (LEFT, RIGHT)"));
+            };
+
+            let mut successors: [NodeId; 2] = Default::default();
+            for (side, step) in [left_step.trim(), right_step.trim()].into_iter().enumerate() {
+                let Some(id) = self.ids.get(step) else {
+                    return Err(SolverError::parse_token(line_index, &line, step, "Step leads to an unknown node"));
+                };
+                successors[side] = *id;
+            }
+            self.transitions.push(successors);
+        }
+
+        return Ok(());
+    }
+
+    pub fn get_instructions(&self) -> &str {
+        return &self.instructions;
+    }
+
+    pub fn get_node_count(&self) -> usize {
+        return self.names.len();
+    }
+
+    pub fn get_name(&self, node: NodeId) -> &str {
+        return &self.names[node as usize];
+    }
+
+    pub fn get_id(&self, name: &str) -> Option<NodeId> {
+        return self.ids.get(name).copied();
+    }
+
+    pub fn get_successors(&self, node: NodeId) -> [NodeId; 2] {
+        return self.transitions[node as usize];
+    }
+
+    pub fn is_end(&self, node: NodeId) -> bool {
+        return self.end_nodes[node as usize];
+    }
+
+    pub fn get_start_nodes(&self) -> Vec<NodeId> {
+        return (0..self.names.len() as NodeId).filter(|x| is_start_node(self.get_name(*x))).collect();
+    }
+
+    //Follows the instruction at the given step count, wrapping around the instruction string
+    pub fn step(&self, node: NodeId, num_steps: u64) -> NodeId {
+        let turn = self.turns[(num_steps % self.turns.len() as u64) as usize];
+        return self.transitions[node as usize][turn];
+    }
+
+    //Walks the given number of steps one at a time, starting from the first instruction
+    pub fn walk(&self, start: NodeId, num_steps: u64) -> NodeId {
+        return (0..num_steps).fold(start, |node, x| self.step(node, x));
+    }
+}
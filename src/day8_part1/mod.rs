@@ -0,0 +1,30 @@
+#![allow(clippy::needless_return)]
+use crate::day8::*;
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::error::SolverError;
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //Parse data
+    let network = DesertNetwork::parse(input)?;
+    end_parse_phase();
+    let (Some(start), Some(finish)) = (network.get_id("AAA"), network.get_id("ZZZ")) else {
+        return Err(SolverError::invalid_input("The map must contain the nodes AAA and ZZZ"));
+    };
+
+    //Print final answer
+    let num_map_steps = calculate_num_map_steps(&network, start, finish);
+    return Ok(Answer::from(num_map_steps));
+}
+
+fn calculate_num_map_steps(network: &DesertNetwork, start: NodeId, finish: NodeId) -> u64 {
+    let mut num_steps = 0u64;
+    let mut current_node = start;
+
+    while current_node != finish {
+        current_node = network.step(current_node, num_steps);
+        num_steps += 1;
+    }
+
+    return num_steps;
+}
@@ -0,0 +1,114 @@
+#![allow(clippy::needless_return)]
+pub mod scalar_solver_iterator;
+
+use crate::day8::*;
+use crate::day8_part2::scalar_solver_iterator::*;
+
+use itertools::Itertools;
+use num::integer::Integer;
+use crate::answer::Answer;
+use crate::congruence::Congruence;
+use crate::bench::end_parse_phase;
+use crate::error::SolverError;
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    //Parse data
+    let network = DesertNetwork::parse(input)?;
+    end_parse_phase();
+
+    if network.get_start_nodes().is_empty() {
+        return Err(SolverError::invalid_input("The map has no starting nodes ending in A"));
+    }
+
+    //Print final answer
+    let num_map_steps = calculate_num_map_steps(&network)?;
+    return Ok(Answer::from(num_map_steps));
+}
+
+fn calculate_num_map_steps(network: &DesertNetwork) -> Result<u64, SolverError> {
+    let ghost_cycles = network
+        .get_start_nodes()
+        .into_iter()
+        .map(|x| ScalarSolverIterator::new(x, network).detect_cycle())
+        .collect::<Vec<GhostCycle>>();
+
+    return find_first_common_hit(&ghost_cycles)?
+        .ok_or_else(|| SolverError::invalid_input("The ghosts never all reach nodes ending in Z at the same time"));
+}
+
+//Most congruences kept while combining ghosts before switching to checking candidate steps one by one
+const MAX_COMBINED_CONGRUENCES: usize = 1 << 16;
+//Most candidate steps checked one by one against the remaining ghosts before giving up
+const MAX_CANDIDATE_CHECKS: usize = 1 << 22;
+
+//Earliest step count, after at least one step, at which every ghost is on a node ending in Z. Fails if there's no
+//such step below the u64 limit but one might exist beyond it, or if finding it needs too many candidate checks.
+pub fn find_first_common_hit(ghost_cycles: &[GhostCycle]) -> Result<Option<u64>, SolverError> {
+    //Until every ghost has reached its cycle the hits don't repeat, so check those steps one by one
+    let Some(cycle_start) = ghost_cycles.iter().map(|x| x.get_prefix_length().max(1)).max() else {
+        return Ok(None);
+    };
+    if let Some(num_steps) = (1..cycle_start).find(|x| ghost_cycles.iter().all(|cycle| cycle.is_hit(*x))) {
+        return Ok(Some(num_steps));
+    }
+
+    //After that each ghost contributes one congruence per hit in its cycle. Combining every pair of them multiplies
+    //the count, so ghosts with few hits go first. Once there would be too many, or the running lcm would overflow,
+    //the remaining ghosts are checked against the candidate steps instead.
+    let ghost_cycles = ghost_cycles.iter().sorted_by_key(|x| x.get_cycle_hits().len()).collect::<Vec<&GhostCycle>>();
+    let mut combined = vec![Congruence::new(0, 1)];
+    let mut num_combined_ghosts = 0;
+    'ghosts: for ghost_cycle in &ghost_cycles {
+        if combined.len() * ghost_cycle.get_cycle_hits().len() > MAX_COMBINED_CONGRUENCES {
+            break;
+        }
+
+        let mut next_combined = Vec::<Congruence>::new();
+        for (congruence, hit) in combined.iter().cartesian_product(ghost_cycle.get_cycle_hits()) {
+            match congruence.combine(&Congruence::new(*hit, ghost_cycle.get_cycle_length())) {
+                Ok(Some(x)) => next_combined.push(x),
+                Ok(None) => (),
+                Err(_) => break 'ghosts
+            }
+        }
+
+        //All the combined congruences share the running lcm as their modulus, so equal ones are duplicates
+        combined = next_combined.into_iter().unique().collect();
+        num_combined_ghosts += 1;
+    }
+
+    let remaining_cycles = &ghost_cycles[num_combined_ghosts..];
+    let mut candidates = combined.iter().map(|x| x.first_at_least(cycle_start)).sorted().collect::<Vec<u64>>();
+    if remaining_cycles.is_empty() || candidates.is_empty() {
+        return Ok(candidates.first().copied());
+    }
+
+    //The candidates repeat every combined modulus steps and every ghost's hits repeat every lcm of all the cycle
+    //lengths, so if that lcm fits in a u64 only the steps up to it need checking
+    let combined_modulus = combined[0].get_modulus();
+    let period = remaining_cycles
+        .iter()
+        .map(|x| x.get_cycle_length())
+        .try_fold(combined_modulus, |lcm, x| lcm.checked_mul(x / lcm.gcd(&x)));
+    let out_of_range = || SolverError::invalid_input("The ghosts don't all reach nodes ending in Z within the u64 limit");
+    let num_rounds = period.unwrap_or(u64::MAX) / combined_modulus;
+    let max_rounds = (MAX_CANDIDATE_CHECKS / candidates.len()) as u64;
+    for _ in 0..num_rounds.min(max_rounds) {
+        if let Some(num_steps) = candidates.iter().find(|x| remaining_cycles.iter().all(|cycle| cycle.is_hit(**x))) {
+            return Ok(Some(*num_steps));
+        }
+
+        for candidate in candidates.iter_mut() {
+            *candidate = candidate.checked_add(combined_modulus).ok_or_else(out_of_range)?;
+        }
+    }
+
+    if num_rounds > max_rounds {
+        return Err(SolverError::invalid_input("The ghosts don't all reach nodes ending in Z within the candidate steps checked"));
+    }
+
+    return match period {
+        Some(_) => Ok(None),
+        None => Err(out_of_range())
+    };
+}
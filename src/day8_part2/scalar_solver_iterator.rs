@@ -1,10 +1,9 @@
-use std::collections::HashMap;
-use crate::step_options::StepOptions;
+#![allow(clippy::needless_return)]
+use crate::day8::*;
 
 pub struct ScalarSolverIterator<'a> {
-    node: &'a String,
-    instructions: &'a String,
-    desert_map: &'a HashMap<String, StepOptions>,
+    node: NodeId,
+    network: &'a DesertNetwork,
     num_steps: u64
 }
 
@@ -14,45 +13,116 @@ pub struct ScalarSolverResult {
     pub iteration: u64
 }
 
+//Every step count at which a ghost stands on a node ending in Z. Once the walk returns to a node at the same
+//instruction index it repeats forever, so the hits after the prefix repeat every cycle_length steps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GhostCycle {
+    prefix_length: u64,
+    cycle_length: u64,
+    //Step counts before the cycle starts
+    prefix_hits: Vec<u64>,
+    //Step counts in prefix_length..prefix_length + cycle_length
+    cycle_hits: Vec<u64>
+}
+
+impl GhostCycle {
+    pub fn get_prefix_length(&self) -> u64 {
+        return self.prefix_length;
+    }
+
+    pub fn get_cycle_length(&self) -> u64 {
+        return self.cycle_length;
+    }
+
+    pub fn get_prefix_hits(&self) -> &[u64] {
+        return &self.prefix_hits;
+    }
+
+    pub fn get_cycle_hits(&self) -> &[u64] {
+        return &self.cycle_hits;
+    }
+
+    //True when the hits are exactly the multiples of the cycle length, which is all the LCM shortcut can handle
+    pub fn is_aligned(&self) -> bool {
+        let expected_prefix_hits = (self.cycle_length..self.prefix_length).step_by(self.cycle_length as usize);
+        let expected_cycle_hit = self.prefix_length.div_ceil(self.cycle_length) * self.cycle_length;
+        return self.prefix_hits.iter().copied().eq(expected_prefix_hits) && self.cycle_hits == [expected_cycle_hit];
+    }
+
+    pub fn is_hit(&self, num_steps: u64) -> bool {
+        if num_steps < self.prefix_length {
+            return self.prefix_hits.contains(&num_steps);
+        }
+
+        let cycle_offset = (num_steps - self.prefix_length) % self.cycle_length;
+        return self.cycle_hits.contains(&(self.prefix_length + cycle_offset));
+    }
+}
+
 impl<'a> ScalarSolverIterator<'a> {
-    pub fn new(
-        start_node: &'a String,
-        instructions: &'a String,
-        desert_map: &'a HashMap<String, StepOptions>) -> ScalarSolverIterator<'a> {
+    pub fn new(start_node: NodeId, network: &'a DesertNetwork) -> ScalarSolverIterator<'a> {
         ScalarSolverIterator {
             node: start_node,
-            instructions,
-            desert_map,
+            network,
             num_steps: 0
         }
     }
+
+    //Walks from the current node until a (node, instruction index) state repeats. Only the nodes at the start of
+    //each pass of the instructions are looked up, so the walk stops at the first pass start seen twice and then
+    //backs up to the step where it first matches itself one cycle later.
+    pub fn detect_cycle(mut self) -> GhostCycle {
+        let start_step = self.num_steps;
+        let mut pass_starts = vec![None; self.network.get_node_count()];
+        //path[i] is the node after start_step + i steps
+        let mut path = Vec::<NodeId>::new();
+
+        loop {
+            if self.get_instruction_index() == 0 {
+                if let Some(first_step) = pass_starts[self.node as usize] {
+                    let cycle_length = self.num_steps - first_step;
+                    let mut prefix_length = first_step;
+                    while prefix_length > start_step
+                        && path[(prefix_length - 1 - start_step) as usize] == path[(prefix_length - 1 - start_step + cycle_length) as usize] {
+                        prefix_length -= 1;
+                    }
+
+                    let hits = (start_step..prefix_length + cycle_length)
+                        .filter(|x| self.network.is_end(path[(x - start_step) as usize]))
+                        .collect::<Vec<u64>>();
+                    let (prefix_hits, cycle_hits) = hits.into_iter().partition(|x| *x < prefix_length);
+                    return GhostCycle { prefix_length, cycle_length, prefix_hits, cycle_hits };
+                }
+                pass_starts[self.node as usize] = Some(self.num_steps);
+            }
+
+            path.push(self.node);
+            self.step();
+        }
+    }
+
+    fn get_instruction_index(&self) -> usize {
+        return (self.num_steps % self.network.get_instructions().len() as u64) as usize;
+    }
+
+    fn step(&mut self) {
+        self.node = self.network.step(self.node, self.num_steps);
+        self.num_steps += 1;
+    }
 }
 
 impl<'a> Iterator for ScalarSolverIterator<'a> {
     type Item = ScalarSolverResult;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let mut first_iteration = true;
-
-        while first_iteration || !self.node.ends_with("Z") {
-            let next_step = self.instructions
-                .chars()
-                .nth(self.num_steps as usize % self.instructions.len())
-                .unwrap();
-
-            self.node = match next_step {
-                'L' => &self.desert_map[self.node].left,
-                'R' => &self.desert_map[self.node].right,
-                _ => panic!("Cannot map instruction")
-            };
-
-            self.num_steps += 1;
-            first_iteration = false;
+        self.step();
+        while !self.network.is_end(self.node) {
+            self.step();
         }
 
         let solver_result = ScalarSolverResult{
-            index: self.num_steps as usize % self.instructions.len(),
-            iteration: self.num_steps / self.instructions.len() as u64
+            index: self.get_instruction_index(),
+            iteration: self.num_steps / self.network.get_instructions().len() as u64
         };
 
         return Some(solver_result);
@@ -1,5 +0,0 @@
-#[derive(Debug)]
-pub struct StepOptions {
-    pub left: String,
-    pub right: String
-}
\ No newline at end of file
@@ -0,0 +1,50 @@
+use std::env;
+use itertools::Itertools;
+use sandbox::day9::*;
+use sandbox::registry::find_solver;
+use sandbox::runner::*;
+
+const USAGE: &str = "\
+Usage: day9 [input path | -] [options]
+
+Options:
+    --direction forward|backward    End of each sequence to extrapolate from (default forward)
+    --count <n>                     How many steps past the end to go (default 1)
+    --list                          Print every extrapolated value of each sequence instead of the sum
+
+Input is read the same way as for day9_part1.";
+
+//Extrapolates the day 9 sequences in either direction, e.g. "day9 --direction backward --count 3"
+fn main() {
+    let mut args = env::args().skip(1).collect::<Vec<String>>();
+    let extrapolation = match take_option(&mut args, "--direction", USAGE) {
+        Some(direction) => direction.parse::<Extrapolation>().unwrap_or_else(|x| exit_with_usage(&x, USAGE)),
+        None => Extrapolation::Forward
+    };
+    let count = match take_option(&mut args, "--count", USAGE) {
+        Some(count) => count.parse::<u64>().unwrap_or_else(|_| exit_with_usage(&format!("Invalid count '{}'", count), USAGE)),
+        None => 1
+    };
+    let list = take_flag(&mut args, "--list");
+
+    let solver = find_solver(9, 1).unwrap();
+    let input = read_positional_input(solver, &args).unwrap_or_else(|x| exit_with_usage(&x, USAGE));
+
+    if list {
+        let sequences = parse_data(&input).unwrap_or_else(|x| exit_with_error(9, &x.to_string()));
+        let count = usize::try_from(count).unwrap_or_else(|_| exit_with_usage("--count is too large to list", USAGE));
+        for sequence in sequences {
+            let values = match extrapolation {
+                Extrapolation::Forward => sequence.extrapolate_forward(count),
+                Extrapolation::Backward => sequence.extrapolate_backward(count)
+            };
+            println!("degree {}: {}", sequence.get_degree(), values.iter().join(" "));
+        }
+        return;
+    }
+
+    match solve_with_extrapolation(&input, extrapolation, count) {
+        Ok(answer) => println!("The sum of the values extrapolated {} {} step(s) is {}", extrapolation, count, answer),
+        Err(error) => exit_with_error(9, &error.to_string())
+    }
+}
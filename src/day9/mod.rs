@@ -0,0 +1,90 @@
+#![allow(clippy::needless_return)]
+pub mod sequence;
+
+use std::fmt;
+use std::str::FromStr;
+use num::{BigInt, BigRational, ToPrimitive};
+use crate::answer::Answer;
+use crate::bench::end_parse_phase;
+use crate::day9::sequence::Sequence;
+use crate::error::*;
+
+//Which end of each sequence to extrapolate from. Part 1 goes forward and part 2 backward.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Extrapolation {
+    #[default]
+    Forward,
+    Backward
+}
+
+impl FromStr for Extrapolation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return match s {
+            "forward" => Ok(Extrapolation::Forward),
+            "backward" => Ok(Extrapolation::Backward),
+            _ => Err(format!("Invalid direction '{}', expected forward or backward", s))
+        };
+    }
+}
+
+impl fmt::Display for Extrapolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            Extrapolation::Forward => write!(f, "forward"),
+            Extrapolation::Backward => write!(f, "backward")
+        };
+    }
+}
+
+//Sums the value count steps past the chosen end of every sequence. A count of 0 sums the end values themselves.
+pub fn solve_with_extrapolation(input: &str, extrapolation: Extrapolation, count: u64) -> Result<Answer, SolverError> {
+    //Parse and calculate answer
+    let data = parse_data(input)?;
+    end_parse_phase();
+
+    let extrapolated_value_sums = data
+        .iter()
+        .map(|x| extrapolate(x, extrapolation, count))
+        .sum::<BigRational>();
+
+    return to_answer(&extrapolated_value_sums);
+}
+
+pub fn extrapolate(sequence: &Sequence, extrapolation: Extrapolation, count: u64) -> BigRational {
+    return match extrapolation {
+        Extrapolation::Forward => sequence.value_at(BigInt::from(sequence.get_values().len() - 1) + count),
+        Extrapolation::Backward => sequence.value_at(-BigInt::from(count))
+    };
+}
+
+//Extrapolated values are always whole numbers since every sequence starts from integers
+pub fn to_answer(value: &BigRational) -> Result<Answer, SolverError> {
+    return match value.to_integer().to_i64() {
+        Some(x) if value.is_integer() => Ok(Answer::from(x)),
+        _ => Err(SolverError::invalid_input(&format!("The result {} does not fit in an i64", value)))
+    };
+}
+
+pub fn parse_data(input: &str) -> Result<Vec<Sequence>, SolverError> {
+    let parse_line_lambda = |line_index: usize, line: &String| -> Result<Sequence, SolverError> {
+        if line.trim().is_empty() {
+            return Err(SolverError::parse_line(line_index, line, "Line has no values"));
+        }
+
+        let values = line
+            .split(" ")
+            .filter(|s| !s.is_empty())
+            .map(|s| parse_token::<i64>(line_index, line, s))
+            .collect::<Result<Vec<i64>, SolverError>>()?;
+        return Sequence::fit(&values).map_err(|x| SolverError::parse_line(line_index, line, &x));
+    };
+
+    return input
+        .lines()
+        .map(String::from)
+        .enumerate()
+        .map(|(line_index, l)| parse_line_lambda(line_index, &l))
+        .collect::<Result<Vec<Sequence>, SolverError>>();
+}
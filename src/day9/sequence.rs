@@ -0,0 +1,85 @@
+#![allow(clippy::needless_return, clippy::ptr_arg)]
+use num::{BigInt, BigRational, One, Zero};
+
+//Lowest degree polynomial through a sequence of values at indices 0, 1, 2, ..., kept in Newton's forward
+//difference form so it can be evaluated exactly at any index
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sequence {
+    values: Vec<BigInt>,
+    //First value of each row of differences, down to the last row that isn't all zeros
+    newton_coefficients: Vec<BigRational>
+}
+
+impl Sequence {
+    //Fails if the differences run out before reaching a row of zeros, since no polynomial of lower degree than the
+    //number of values is then known to describe the sequence
+    pub fn fit(values: &[i64]) -> Result<Self, String> {
+        let values = values.iter().map(|x| BigInt::from(*x)).collect::<Vec<BigInt>>();
+        let diff_tree = generate_diff_tree(&values);
+        if diff_tree.last().unwrap().is_empty() {
+            return Err(String::from("The differences never reach a row of zeros"));
+        }
+
+        let newton_coefficients = diff_tree[..diff_tree.len() - 1]
+            .iter()
+            .map(|x| BigRational::from_integer(x[0].clone()))
+            .collect::<Vec<BigRational>>();
+
+        return Ok(Sequence { values, newton_coefficients });
+    }
+
+    pub fn get_values(&self) -> &[BigInt] {
+        return &self.values;
+    }
+
+    //A constant sequence, including all zeros, has degree 0
+    pub fn get_degree(&self) -> usize {
+        return self.newton_coefficients.len().saturating_sub(1);
+    }
+
+    pub fn get_newton_coefficients(&self) -> &[BigRational] {
+        return &self.newton_coefficients;
+    }
+
+    //Sum of each coefficient times the binomial coefficient (index choose k), which also holds for negative indices
+    pub fn value_at<T: Into<BigInt>>(&self, index: T) -> BigRational {
+        let index = BigRational::from_integer(index.into());
+        let mut value = BigRational::zero();
+        let mut binomial = BigRational::one();
+        for (k, coefficient) in self.newton_coefficients.iter().enumerate() {
+            value += coefficient * &binomial;
+            binomial = binomial * (&index - BigRational::from_integer(BigInt::from(k))) / BigRational::from_integer(BigInt::from(k + 1));
+        }
+
+        return value;
+    }
+
+    //The count values after the last one, nearest first
+    pub fn extrapolate_forward(&self, count: usize) -> Vec<BigRational> {
+        let length = self.values.len();
+        return (length..length + count).map(|x| self.value_at(x)).collect();
+    }
+
+    //The count values before the first one, nearest first
+    pub fn extrapolate_backward(&self, count: usize) -> Vec<BigRational> {
+        return (1..=count).map(|x| self.value_at(-BigInt::from(x))).collect();
+    }
+}
+
+//Rows of differences between neighbouring values, stopping at a row of zeros or an empty row
+pub fn generate_diff_tree(line: &Vec<BigInt>) -> Vec<Vec<BigInt>> {
+    let mut diff_tree = Vec::<Vec<BigInt>>::new();
+    diff_tree.push(line.clone());
+
+    while !diff_tree.last().unwrap().iter().all(|x| x.is_zero()) {
+        let last_layer = diff_tree.last().unwrap();
+        let diff_layer = last_layer
+            .windows(2)
+            .map(|x| &x[1] - &x[0])
+            .collect::<Vec<BigInt>>();
+
+        diff_tree.push(diff_layer);
+    }
+
+    return diff_tree;
+}
@@ -0,0 +1,8 @@
+#![allow(clippy::needless_return)]
+use crate::answer::Answer;
+use crate::day9::*;
+use crate::error::SolverError;
+
+pub fn solve(input: &str) -> Result<Answer, SolverError> {
+    return solve_with_extrapolation(input, Extrapolation::Forward, 1);
+}
@@ -0,0 +1,177 @@
+#![allow(clippy::needless_return)]
+use crate::grid::Position;
+use Direction::*;
+
+//Positions follow the grid convention of (row, column) with rows growing south and columns growing east.
+//Signed positions use the same convention for maps that extend past the top or left edge.
+pub type SignedPosition = (isize, isize);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction { North, East, South, West }
+
+impl Direction {
+    //Clockwise from north
+    pub const ALL: [Direction; 4] = [North, East, South, West];
+
+    //Parses the U/R/D/L letters used by instruction style inputs
+    pub fn from_letter(letter: char) -> Option<Self> {
+        return match letter {
+            'U' => Some(North),
+            'R' => Some(East),
+            'D' => Some(South),
+            'L' => Some(West),
+            _ => None
+        };
+    }
+
+    //(row, column) offset of one step
+    pub fn get_delta(&self) -> (isize, isize) {
+        return match self {
+            North => (-1, 0),
+            East => (0, 1),
+            South => (1, 0),
+            West => (0, -1)
+        };
+    }
+
+    pub fn turn_left(&self) -> Self {
+        return match self {
+            North => West,
+            East => North,
+            South => East,
+            West => South
+        };
+    }
+
+    pub fn turn_right(&self) -> Self {
+        return match self {
+            North => East,
+            East => South,
+            South => West,
+            West => North
+        };
+    }
+
+    pub fn reverse(&self) -> Self {
+        return match self {
+            North => South,
+            East => West,
+            South => North,
+            West => East
+        };
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        return *self == North || *self == South;
+    }
+
+    //Direction of a neighboring position. Vertical moves win if the positions differ in both row and column.
+    pub fn between(from: &Position, to: &Position) -> Self {
+        return if to.0 < from.0 {
+            North
+        } else if to.0 > from.0 {
+            South
+        } else if to.1 < from.1 {
+            West
+        } else {
+            East
+        };
+    }
+
+    pub fn advance(&self, position: SignedPosition) -> SignedPosition {
+        let delta = self.get_delta();
+        return (position.0 + delta.0, position.1 + delta.1);
+    }
+
+    //Returns None if the step would leave the top or left edge. Use Grid::step to also check the bottom and right edges.
+    pub fn checked_advance(&self, position: Position) -> Option<Position> {
+        let delta = self.get_delta();
+        return Some((position.0.checked_add_signed(delta.0)?, position.1.checked_add_signed(delta.1)?));
+    }
+}
+
+//The four cardinal and four ordinal directions
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CompassDirection {
+    North,
+    Northeast,
+    East,
+    Southeast,
+    South,
+    Southwest,
+    West,
+    Northwest
+}
+
+impl CompassDirection {
+    //Clockwise from north
+    pub const ALL: [CompassDirection; 8] = [CompassDirection::North, CompassDirection::Northeast, CompassDirection::East,
+        CompassDirection::Southeast, CompassDirection::South, CompassDirection::Southwest, CompassDirection::West, CompassDirection::Northwest];
+
+    //(row, column) offset of one step
+    pub fn get_delta(&self) -> (isize, isize) {
+        return match self {
+            CompassDirection::North => (-1, 0),
+            CompassDirection::Northeast => (-1, 1),
+            CompassDirection::East => (0, 1),
+            CompassDirection::Southeast => (1, 1),
+            CompassDirection::South => (1, 0),
+            CompassDirection::Southwest => (1, -1),
+            CompassDirection::West => (0, -1),
+            CompassDirection::Northwest => (-1, -1)
+        };
+    }
+
+    //90 degree turns, matching Direction
+    pub fn turn_left(&self) -> Self {
+        return CompassDirection::ALL[(self.get_index() + 6) % 8];
+    }
+
+    pub fn turn_right(&self) -> Self {
+        return CompassDirection::ALL[(self.get_index() + 2) % 8];
+    }
+
+    //45 degree turns onto the neighboring compass point
+    pub fn turn_left_45(&self) -> Self {
+        return CompassDirection::ALL[(self.get_index() + 7) % 8];
+    }
+
+    pub fn turn_right_45(&self) -> Self {
+        return CompassDirection::ALL[(self.get_index() + 1) % 8];
+    }
+
+    pub fn reverse(&self) -> Self {
+        return CompassDirection::ALL[(self.get_index() + 4) % 8];
+    }
+
+    //Whether the direction has a component along the cardinal direction, e.g. northeast points north and east
+    pub fn points_towards(&self, direction: Direction) -> bool {
+        let delta = self.get_delta();
+        let cardinal_delta = direction.get_delta();
+        return delta.0 * cardinal_delta.0 + delta.1 * cardinal_delta.1 > 0;
+    }
+
+    fn get_index(&self) -> usize {
+        return CompassDirection::ALL.iter().position(|x| x == self).unwrap();
+    }
+}
+
+impl From<Direction> for CompassDirection {
+    fn from(direction: Direction) -> Self {
+        return match direction {
+            North => CompassDirection::North,
+            East => CompassDirection::East,
+            South => CompassDirection::South,
+            West => CompassDirection::West
+        };
+    }
+}
+
+pub fn to_signed_position(position: Position) -> SignedPosition {
+    return (position.0 as isize, position.1 as isize);
+}
+
+//Returns None for positions above or left of the origin
+pub fn to_unsigned_position(position: SignedPosition) -> Option<Position> {
+    return Some((usize::try_from(position.0).ok()?, usize::try_from(position.1).ok()?));
+}
@@ -0,0 +1,112 @@
+#![allow(clippy::needless_return)]
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+//Error returned by a solver when its input is malformed. The day is filled in by Solver::run since the
+//day modules don't know which day they are registered as.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolverError {
+    //A specific piece of text couldn't be parsed. Line and column are 1-based.
+    Parse {
+        day: Option<u32>,
+        line: usize,
+        column: usize,
+        text: String,
+        reason: String
+    },
+    //The input parsed but doesn't describe a solvable puzzle, e.g. a missing start tile
+    InvalidInput {
+        day: Option<u32>,
+        reason: String
+    }
+}
+
+impl SolverError {
+    //line_index and column_index are 0-based, as produced by enumerate()
+    pub fn parse(line_index: usize, column_index: usize, text: &str, reason: &str) -> Self {
+        return SolverError::Parse {
+            day: None,
+            line: line_index + 1,
+            column: column_index + 1,
+            text: text.to_string(),
+            reason: reason.to_string()
+        };
+    }
+
+    //Builds a parse error for a token within a line, working out the column from where the token sits
+    pub fn parse_token(line_index: usize, line: &str, token: &str, reason: &str) -> Self {
+        return SolverError::parse(line_index, find_column(line, token), token, reason);
+    }
+
+    //Builds a parse error for the character starting at byte_index, e.g. as returned by str::find
+    pub fn parse_char(line_index: usize, line: &str, byte_index: usize, reason: &str) -> Self {
+        let symbol = line[byte_index..].chars().next().unwrap_or_default();
+        return SolverError::parse(line_index, line[..byte_index].chars().count(), &symbol.to_string(), reason);
+    }
+
+    //Builds a parse error covering a whole line
+    pub fn parse_line(line_index: usize, line: &str, reason: &str) -> Self {
+        return SolverError::parse(line_index, 0, line, reason);
+    }
+
+    pub fn invalid_input(reason: &str) -> Self {
+        return SolverError::InvalidInput {
+            day: None,
+            reason: reason.to_string()
+        };
+    }
+
+    pub fn get_day(&self) -> Option<u32> {
+        return match self {
+            SolverError::Parse { day, .. } => *day,
+            SolverError::InvalidInput { day, .. } => *day
+        };
+    }
+
+    pub fn with_day(mut self, new_day: u32) -> Self {
+        match &mut self {
+            SolverError::Parse { day, .. } => *day = Some(new_day),
+            SolverError::InvalidInput { day, .. } => *day = Some(new_day)
+        }
+        return self;
+    }
+}
+
+impl fmt::Display for SolverError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.get_day() {
+            write!(f, "Day {}: ", day)?;
+        }
+
+        return match self {
+            SolverError::Parse { line, column, text, reason, .. } =>
+                write!(f, "{} at line {}, column {}: '{}'", reason, line, column, text),
+            SolverError::InvalidInput { reason, .. } => write!(f, "{}", reason)
+        };
+    }
+}
+
+impl Error for SolverError {}
+
+//Parses a token within a line, reporting its position if it isn't a valid T
+pub fn parse_token<T: FromStr>(line_index: usize, line: &str, token: &str) -> Result<T, SolverError> {
+    return token
+        .trim()
+        .parse::<T>()
+        .map_err(|_| SolverError::parse_token(line_index, line, token, "Invalid number"));
+}
+
+//Uses the token's address if it was sliced from the line, otherwise falls back to searching for it. The result
+//is in characters rather than bytes.
+fn find_column(line: &str, token: &str) -> usize {
+    let line_start = line.as_ptr() as usize;
+    let token_start = token.as_ptr() as usize;
+    let byte_index = if token_start >= line_start && token_start + token.len() <= line_start + line.len() {
+        token_start - line_start
+    } else {
+        line.find(token).unwrap_or(0)
+    };
+
+    return line[..byte_index].chars().count();
+}
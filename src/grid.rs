@@ -0,0 +1,252 @@
+#![allow(clippy::needless_return)]
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use crate::error::SolverError;
+
+//Position of a cell as (row, column), with row 0 at the top
+pub type Position = (usize, usize);
+
+//(row, column) offsets of the 4-connected neighbors, clockwise from north
+pub const NEIGHBOR_OFFSETS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+//(row, column) offsets of the 8-connected neighbors in reading order
+pub const NEIGHBOR_OFFSETS_8: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+
+//Rectangular grid stored in row-major order
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>
+}
+
+impl<T> Grid<T> {
+    //Returns None if the rows aren't all the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, |x| x.len());
+        if rows.iter().any(|x| x.len() != width) {
+            return None;
+        }
+
+        return Some(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect()
+        });
+    }
+
+    //Parses one cell per character, reporting the position of any character parse_cell rejects
+    pub fn parse(input: &str, parse_cell: impl Fn(char) -> Option<T>) -> Result<Self, SolverError> {
+        return Grid::parse_lines(input.lines().enumerate(), parse_cell);
+    }
+
+    //Like parse, but for lines taken from part of a larger input. Each line comes with its index in the input
+    //so that errors point at the right line.
+    pub fn parse_lines<'a>(lines: impl IntoIterator<Item = (usize, &'a str)>, parse_cell: impl Fn(char) -> Option<T>) -> Result<Self, SolverError> {
+        let mut width = None;
+        let mut height = 0usize;
+        let mut cells = Vec::<T>::new();
+
+        for (line_index, line) in lines {
+            let row_start = cells.len();
+            for (column_index, symbol) in line.chars().enumerate() {
+                let Some(cell) = parse_cell(symbol) else {
+                    return Err(SolverError::parse(line_index, column_index, &symbol.to_string(), "Invalid symbol"));
+                };
+                cells.push(cell);
+            }
+
+            let row_width = cells.len() - row_start;
+            if *width.get_or_insert(row_width) != row_width {
+                return Err(SolverError::parse_line(line_index, line, "Row length differs from the first row"));
+            }
+            height += 1;
+        }
+
+        return match width {
+            Some(width) if width > 0 => Ok(Grid { width, height, cells }),
+            _ => Err(SolverError::invalid_input("Input is empty"))
+        };
+    }
+
+    pub fn get_width(&self) -> usize {
+        return self.width;
+    }
+
+    pub fn get_height(&self) -> usize {
+        return self.height;
+    }
+
+    pub fn is_in_bounds(&self, row: isize, column: isize) -> bool {
+        return row >= 0 && column >= 0 && (row as usize) < self.height && (column as usize) < self.width;
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        return self.get_index(position).map(|x| &self.cells[x]);
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        return self.get_index(position).map(|x| &mut self.cells[x]);
+    }
+
+    //Moves by a (row, column) offset, returning None if that leaves the grid
+    pub fn step(&self, position: Position, offset: (isize, isize)) -> Option<Position> {
+        let row = position.0 as isize + offset.0;
+        let column = position.1 as isize + offset.1;
+        return if self.is_in_bounds(row, column) { Some((row as usize, column as usize)) } else { None };
+    }
+
+    //Maps a position on an infinitely repeating copy of the grid back onto the grid
+    pub fn wrap(&self, row: isize, column: isize) -> Position {
+        return (row.rem_euclid(self.height as isize) as usize, column.rem_euclid(self.width as isize) as usize);
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        return &self.cells[row * self.width..(row + 1) * self.width];
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        return self.cells.chunks_exact(self.width.max(1));
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        return self.cells.iter().skip(column).step_by(self.width.max(1));
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        return (0..self.width).map(|x| self.column(x));
+    }
+
+    //Every position in reading order
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        return (0..self.cells.len()).map(move |x| (x / width, x % width));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        return self.positions().zip(self.cells.iter());
+    }
+
+    //First position in reading order whose cell matches
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Position> {
+        return self.cells.iter().position(predicate).map(|x| (x / self.width, x % self.width));
+    }
+
+    pub fn neighbors4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        return NEIGHBOR_OFFSETS_4.into_iter().filter_map(move |x| self.step(position, x));
+    }
+
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        return NEIGHBOR_OFFSETS_8.into_iter().filter_map(move |x| self.step(position, x));
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        return Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect()
+        };
+    }
+
+    pub fn map_with_position<U>(&self, mut f: impl FnMut(Position, &T) -> U) -> Grid<U> {
+        return Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.iter().map(|(position, cell)| f(position, cell)).collect()
+        };
+    }
+
+    //Draws one character per cell with a newline after each row
+    pub fn render(&self, f: impl Fn(&T) -> char) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            text.extend(row.iter().map(&f));
+            text.push('\n');
+        }
+
+        return text;
+    }
+
+    fn get_index(&self, position: Position) -> Option<usize> {
+        return if position.0 < self.height && position.1 < self.width {
+            Some(position.0 * self.width + position.1)
+        } else {
+            None
+        };
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        return Grid { width, height, cells: vec![value; width * height] };
+    }
+
+    //Swaps rows and columns
+    pub fn transpose(&self) -> Self {
+        return Grid {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect()
+        };
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        return Grid {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flat_map(|x| x.collect::<Vec<&T>>().into_iter().rev()).cloned().collect()
+        };
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self {
+        return Grid {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width).rev().flat_map(|x| self.column(x)).cloned().collect()
+        };
+    }
+
+    //Inserts a row filled with value before the given row
+    pub fn insert_row(&mut self, row: usize, value: T) {
+        let index = row * self.width;
+        self.cells.splice(index..index, vec![value; self.width]);
+        self.height += 1;
+    }
+
+    //Inserts a column filled with value before the given column
+    pub fn insert_column(&mut self, column: usize, value: T) {
+        for row in (0..self.height).rev() {
+            self.cells.insert(row * self.width + column, value.clone());
+        }
+        self.width += 1;
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        let index = self.get_index(position).unwrap_or_else(|| panic!("Position {:?} is outside the {}x{} grid", position, self.height, self.width));
+        return &self.cells[index];
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let index = self.get_index(position).unwrap_or_else(|| panic!("Position {:?} is outside the {}x{} grid", position, self.height, self.width));
+        return &mut self.cells[index];
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,56 @@
+#![allow(clippy::needless_return)]
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::io::Read;
+use std::path::PathBuf;
+use crate::registry::Solver;
+
+pub const INPUT_DIR_VARIABLE: &str = "AOC_INPUT_DIR";
+pub const STDIN_ARGUMENT: &str = "-";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>")
+        };
+    }
+}
+
+impl InputSource {
+    //Resolution order is the command line argument, then AOC_INPUT_DIR, then the solver's default path
+    pub fn resolve(solver: &Solver, argument: Option<&str>) -> Self {
+        if let Some(argument) = argument {
+            return if argument == STDIN_ARGUMENT {
+                InputSource::Stdin
+            } else {
+                InputSource::File(PathBuf::from(argument))
+            };
+        }
+
+        if let Some(input_dir) = env::var_os(INPUT_DIR_VARIABLE) {
+            return InputSource::File(PathBuf::from(input_dir).join(format!("day{}.txt", solver.day)));
+        }
+
+        return InputSource::File(PathBuf::from(solver.default_input));
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        return match self {
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        };
+    }
+}
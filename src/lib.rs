@@ -0,0 +1,69 @@
+pub mod answer;
+pub mod bench;
+pub mod congruence;
+pub mod direction;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod output;
+pub mod pathfinding;
+pub mod range_set;
+pub mod registry;
+pub mod runner;
+pub mod verify;
+
+pub mod day1_part1;
+pub mod day1_part2;
+pub mod day2_part1;
+pub mod day2_part2;
+pub mod day3_part1;
+pub mod day3_part2;
+pub mod day4_part1;
+pub mod day4_part2;
+pub mod day5;
+pub mod day5_part1;
+pub mod day5_part2;
+pub mod day6_part1;
+pub mod day6_part2;
+pub mod day7;
+pub mod day7_part1;
+pub mod day7_part2;
+pub mod day8;
+pub mod day8_part1;
+pub mod day8_part2;
+pub mod day9;
+pub mod day9_part1;
+pub mod day9_part2;
+pub mod day10;
+pub mod day10_part1;
+pub mod day10_part2;
+pub mod day11_part1;
+pub mod day11_part2;
+pub mod day12_part1;
+pub mod day12_part2;
+pub mod day13_part1;
+pub mod day13_part2;
+pub mod day14_part1;
+pub mod day14_part2;
+pub mod day15_part1;
+pub mod day15_part2;
+pub mod day16_part1;
+pub mod day16_part2;
+pub mod day17;
+pub mod day17_part1;
+pub mod day17_part2;
+pub mod day18_part1;
+pub mod day18_part2;
+pub mod day19_part1;
+pub mod day19_part2;
+pub mod day20_part1;
+pub mod day20_part2;
+pub mod day21_part1;
+pub mod day21_part2;
+pub mod day22_part1;
+pub mod day22_part2;
+pub mod day23_part1;
+pub mod day23_part2;
+pub mod day24_part1;
+pub mod day24_part2;
+pub mod day25;
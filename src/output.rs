@@ -0,0 +1,71 @@
+#![allow(clippy::needless_return)]
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+use serde::Serialize;
+use crate::answer::Answer;
+use crate::registry::Solver;
+
+pub const FORMAT_OPTION: &str = "--format";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    //The solver's sentence, e.g. "The minimum heat loss is 1,215"
+    #[default]
+    Text,
+    //One JSON object per line, for scripts and dashboards
+    Json,
+    //Just the answer without digit grouping
+    Plain
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "plain" => Ok(OutputFormat::Plain),
+            _ => Err(format!("Invalid format '{}', expected text, json, or plain", s))
+        };
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            OutputFormat::Text => write!(f, "text"),
+            OutputFormat::Json => write!(f, "json"),
+            OutputFormat::Plain => write!(f, "plain")
+        };
+    }
+}
+
+//Result of a single solver run, as emitted by the JSON format
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SolverReport {
+    pub day: u32,
+    pub part: u32,
+    pub answer: i64,
+    pub elapsed_ms: f64
+}
+
+impl SolverReport {
+    pub fn new(solver: &Solver, answer: Answer, elapsed: Duration) -> Self {
+        return SolverReport {
+            day: solver.day,
+            part: solver.part,
+            answer: answer.get_value(),
+            elapsed_ms: elapsed.as_secs_f64() * 1000f64
+        };
+    }
+}
+
+pub fn format_answer(solver: &Solver, answer: Answer, elapsed: Duration, format: OutputFormat) -> String {
+    return match format {
+        OutputFormat::Text => solver.describe(&answer),
+        OutputFormat::Json => serde_json::to_string(&SolverReport::new(solver, answer, elapsed)).unwrap(),
+        OutputFormat::Plain => answer.get_value().to_string()
+    };
+}
@@ -0,0 +1,161 @@
+#![allow(clippy::needless_return)]
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+use std::ops::Add;
+
+//Cheapest route found by a search. The path starts with a start node and ends with the goal node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchResult<N, C> {
+    cost: C,
+    path: Vec<N>
+}
+
+impl<N, C: Copy> SearchResult<N, C> {
+    pub fn get_cost(&self) -> C {
+        return self.cost;
+    }
+
+    pub fn get_path(&self) -> &[N] {
+        return &self.path;
+    }
+
+    pub fn get_goal(&self) -> &N {
+        return self.path.last().expect("A path always contains the start node");
+    }
+
+    pub fn into_path(self) -> Vec<N> {
+        return self.path;
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+struct HeapState<C> {
+    estimate: C,
+    cost: C,
+    node_index: usize,
+}
+
+//Flip the ordering so that BinaryHeap pops the lowest estimate first. Ties go to the node with the higher
+//cost so far since it is likely closer to a goal, then to the node index to keep Ord consistent with Eq.
+impl<C: Ord> Ord for HeapState<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        return other.estimate.cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+            .then_with(|| self.node_index.cmp(&other.node_index));
+    }
+}
+
+impl<C: Ord> PartialOrd for HeapState<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+//Dijkstra's algorithm over an implicit graph. successors yields each neighbor of a node along with the cost
+//of moving to it, and the search stops at the first node accepted by is_goal. Returns None if no goal is reachable.
+pub fn dijkstra<N, C, S, I, G>(start: N, successors: S, is_goal: G) -> Option<SearchResult<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    G: FnMut(&N) -> bool
+{
+    return astar(start, successors, |_| C::default(), is_goal);
+}
+
+//A* search over an implicit graph. heuristic must never overestimate the remaining cost to the nearest goal,
+//otherwise the returned path may not be the cheapest one.
+pub fn astar<N, C, S, I, H, G>(start: N, successors: S, heuristic: H, is_goal: G) -> Option<SearchResult<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool
+{
+    return astar_from_any([start], successors, heuristic, is_goal);
+}
+
+//Like astar, but the search may begin at any of the start nodes at no cost. The path starts with whichever
+//start node the cheapest route leaves from.
+pub fn astar_from_any<N, C, T, S, I, H, G>(starts: T, mut successors: S, mut heuristic: H, mut is_goal: G) -> Option<SearchResult<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    T: IntoIterator<Item = N>,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool
+{
+    //Nodes are numbered as they are discovered so that the bookkeeping can live in plain vectors
+    let mut nodes = Vec::<N>::new();
+    let mut node_indices = HashMap::<N, usize>::new();
+    let mut costs = Vec::<C>::new();
+    let mut previous = Vec::<Option<usize>>::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        if node_indices.contains_key(&start) {
+            continue;
+        }
+
+        let node_index = nodes.len();
+        node_indices.insert(start.clone(), node_index);
+        heap.push(HeapState { estimate: heuristic(&start), cost: C::default(), node_index });
+        nodes.push(start);
+        costs.push(C::default());
+        previous.push(None);
+    }
+
+    while let Some(HeapState { cost, node_index, .. }) = heap.pop() {
+        //Skip stale entries for nodes that have since been reached more cheaply
+        if cost > costs[node_index] {
+            continue;
+        }
+
+        if is_goal(&nodes[node_index]) {
+            return Some(SearchResult { cost, path: reconstruct_path(&nodes, &previous, node_index) });
+        }
+
+        for (successor, step_cost) in successors(&nodes[node_index]) {
+            let next_cost = cost + step_cost;
+            let successor_index = match node_indices.get(&successor) {
+                Some(&index) if next_cost >= costs[index] => continue,
+                Some(&index) => {
+                    costs[index] = next_cost;
+                    previous[index] = Some(node_index);
+                    index
+                },
+                None => {
+                    let index = nodes.len();
+                    node_indices.insert(successor.clone(), index);
+                    nodes.push(successor);
+                    costs.push(next_cost);
+                    previous.push(Some(node_index));
+                    index
+                }
+            };
+
+            let estimate = next_cost + heuristic(&nodes[successor_index]);
+            heap.push(HeapState { estimate, cost: next_cost, node_index: successor_index });
+        }
+    }
+
+    return None;
+}
+
+fn reconstruct_path<N: Clone>(nodes: &[N], previous: &[Option<usize>], goal_index: usize) -> Vec<N> {
+    let mut path = Vec::<N>::new();
+    let mut current = Some(goal_index);
+    while let Some(index) = current {
+        path.push(nodes[index].clone());
+        current = previous[index];
+    }
+    path.reverse();
+
+    return path;
+}
@@ -0,0 +1,273 @@
+#![allow(clippy::needless_return)]
+use std::cmp::{max, min};
+use std::ops::Range;
+
+//Set of integers stored as sorted, disjoint ranges. Ranges are half-open, so 3..5 holds 3 and 4. Touching
+//ranges are merged, which keeps the representation of a set unique.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeSet {
+    ranges: Vec<Range<u64>>
+}
+
+impl RangeSet {
+    pub fn new() -> Self {
+        return RangeSet { ranges: Vec::<Range<u64>>::new() };
+    }
+
+    pub fn get_ranges(&self) -> &[Range<u64>] {
+        return &self.ranges;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.ranges.is_empty();
+    }
+
+    //Number of integers in the set
+    pub fn get_size(&self) -> u64 {
+        return self.ranges.iter().map(|x| x.end - x.start).sum();
+    }
+
+    pub fn get_min(&self) -> Option<u64> {
+        return self.ranges.first().map(|x| x.start);
+    }
+
+    pub fn contains(&self, value: u64) -> bool {
+        let index = self.ranges.partition_point(|x| x.end <= value);
+        return self.ranges.get(index).is_some_and(|x| x.contains(&value));
+    }
+
+    pub fn insert(&mut self, range: Range<u64>) {
+        if range.is_empty() {
+            return;
+        }
+
+        //Every range from first_index up to last_index overlaps or touches the new range
+        let first_index = self.ranges.partition_point(|x| x.end < range.start);
+        let last_index = self.ranges.partition_point(|x| x.start <= range.end);
+        let merged = if first_index < last_index {
+            min(range.start, self.ranges[first_index].start)..max(range.end, self.ranges[last_index - 1].end)
+        } else {
+            range
+        };
+
+        self.ranges.splice(first_index..last_index, [merged]);
+    }
+
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        let mut result = self.clone();
+        for range in &other.ranges {
+            result.insert(range.clone());
+        }
+
+        return result;
+    }
+
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        let mut result = RangeSet::new();
+        let (mut self_index, mut other_index) = (0usize, 0usize);
+        while self_index < self.ranges.len() && other_index < other.ranges.len() {
+            let self_range = &self.ranges[self_index];
+            let other_range = &other.ranges[other_index];
+            let overlap = max(self_range.start, other_range.start)..min(self_range.end, other_range.end);
+            if !overlap.is_empty() {
+                result.ranges.push(overlap);
+            }
+
+            //Move past whichever range ends first since it can't overlap anything further on
+            if self_range.end <= other_range.end {
+                self_index += 1;
+            } else {
+                other_index += 1;
+            }
+        }
+
+        return result;
+    }
+
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        let mut result = RangeSet::new();
+        let mut other_index = 0usize;
+        for range in &self.ranges {
+            let mut cursor = range.start;
+            while other_index < other.ranges.len() && other.ranges[other_index].end <= cursor {
+                other_index += 1;
+            }
+
+            //Cut out each range of other that overlaps this one. The last of them may also overlap the next range.
+            let mut index = other_index;
+            while index < other.ranges.len() && other.ranges[index].start < range.end {
+                if other.ranges[index].start > cursor {
+                    result.ranges.push(cursor..other.ranges[index].start);
+                }
+                cursor = max(cursor, other.ranges[index].end);
+                index += 1;
+            }
+
+            if cursor < range.end {
+                result.ranges.push(cursor..range.end);
+            }
+        }
+
+        return result;
+    }
+}
+
+impl From<Range<u64>> for RangeSet {
+    fn from(range: Range<u64>) -> Self {
+        let mut result = RangeSet::new();
+        result.insert(range);
+        return result;
+    }
+}
+
+impl FromIterator<Range<u64>> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = Range<u64>>>(iter: I) -> Self {
+        let mut result = RangeSet::new();
+        for range in iter {
+            result.insert(range);
+        }
+
+        return result;
+    }
+}
+
+//Piecewise-linear map made of source ranges that are each shifted onto a destination range of the same
+//length. Values outside every source range map to themselves.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeMap {
+    //Sorted by source start, with no two sources overlapping
+    entries: Vec<RangeMapEntry>
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeMapEntry {
+    pub source: Range<u64>,
+    pub destination_start: u64
+}
+
+impl RangeMapEntry {
+    pub fn get_destination(&self) -> Range<u64> {
+        return self.destination_start..self.destination_start + (self.source.end - self.source.start);
+    }
+}
+
+impl RangeMap {
+    pub fn new() -> Self {
+        return RangeMap { entries: Vec::<RangeMapEntry>::new() };
+    }
+
+    pub fn get_entries(&self) -> &[RangeMapEntry] {
+        return &self.entries;
+    }
+
+    //Maps source onto the range starting at destination_start. Returns false and leaves the map unchanged
+    //if source overlaps an existing entry.
+    pub fn insert(&mut self, source: Range<u64>, destination_start: u64) -> bool {
+        if source.is_empty() {
+            return true;
+        }
+
+        let index = self.entries.partition_point(|x| x.source.end <= source.start);
+        if self.entries.get(index).is_some_and(|x| x.source.start < source.end) {
+            return false;
+        }
+
+        self.entries.insert(index, RangeMapEntry { source, destination_start });
+        return true;
+    }
+
+    pub fn map_value(&self, value: u64) -> u64 {
+        let index = self.entries.partition_point(|x| x.source.end <= value);
+        return match self.entries.get(index) {
+            Some(entry) if entry.source.contains(&value) => entry.destination_start + (value - entry.source.start),
+            _ => value
+        };
+    }
+
+    pub fn map_ranges(&self, ranges: &RangeSet) -> RangeSet {
+        return ranges.ranges
+            .iter()
+            .flat_map(|x| self.get_pieces(x.clone()))
+            .map(|x| x.get_destination())
+            .collect();
+    }
+
+    //Every value that maps into ranges. Several sources can share a destination, so this is a set rather than
+    //a single range per input range.
+    pub fn preimage(&self, ranges: &RangeSet) -> RangeSet {
+        let mut result = RangeSet::new();
+        for piece in self.get_pieces(0..u64::MAX) {
+            let destination = piece.get_destination();
+            let first_index = ranges.ranges.partition_point(|x| x.end <= destination.start);
+            for range in ranges.ranges[first_index..].iter().take_while(|x| x.start < destination.end) {
+                let overlap = max(range.start, destination.start)..min(range.end, destination.end);
+                let source_start = piece.source.start + (overlap.start - destination.start);
+                result.insert(source_start..source_start + (overlap.end - overlap.start));
+            }
+        }
+
+        return result;
+    }
+
+    //Map that undoes this one over 0..u64::MAX, or None if two values share a destination
+    pub fn inverse(&self) -> Option<RangeMap> {
+        let mut result = RangeMap::new();
+        for piece in self.get_pieces(0..u64::MAX) {
+            if !result.insert(piece.get_destination(), piece.source.start) {
+                return None;
+            }
+        }
+
+        //Identity pieces were only needed to check for collisions
+        result.entries.retain(|x| x.source.start != x.destination_start);
+        return Some(result);
+    }
+
+    //Map equivalent to applying this map and then next
+    pub fn compose(&self, next: &RangeMap) -> RangeMap {
+        let mut result = RangeMap::new();
+        for piece in self.get_pieces(0..u64::MAX) {
+            for next_piece in next.get_pieces(piece.get_destination()) {
+                let source_start = piece.source.start + (next_piece.source.start - piece.destination_start);
+                let source = source_start..source_start + (next_piece.source.end - next_piece.source.start);
+
+                //Pieces that end up where they started are already covered by the identity mapping
+                if source.start != next_piece.destination_start {
+                    result.entries.push(RangeMapEntry { source, destination_start: next_piece.destination_start });
+                }
+            }
+        }
+
+        return result;
+    }
+
+    //Splits range into consecutive pieces that are each shifted by a single amount, including identity pieces
+    //for the gaps between entries
+    fn get_pieces(&self, range: Range<u64>) -> Vec<RangeMapEntry> {
+        let mut pieces = Vec::<RangeMapEntry>::new();
+        let mut cursor = range.start;
+        let mut index = self.entries.partition_point(|x| x.source.end <= range.start);
+
+        while cursor < range.end {
+            match self.entries.get(index) {
+                Some(entry) if entry.source.start <= cursor => {
+                    let end = min(entry.source.end, range.end);
+                    pieces.push(RangeMapEntry { source: cursor..end, destination_start: entry.destination_start + (cursor - entry.source.start) });
+                    cursor = end;
+                    index += 1;
+                },
+                Some(entry) => {
+                    let end = min(entry.source.start, range.end);
+                    pieces.push(RangeMapEntry { source: cursor..end, destination_start: cursor });
+                    cursor = end;
+                },
+                None => {
+                    pieces.push(RangeMapEntry { source: cursor..range.end, destination_start: cursor });
+                    cursor = range.end;
+                }
+            }
+        }
+
+        return pieces;
+    }
+}
@@ -0,0 +1,381 @@
+#![allow(clippy::needless_return)]
+use crate::answer::Answer;
+use crate::error::SolverError;
+
+//Entry point for a single day/part solver
+pub struct Solver {
+    pub day: u32,
+    pub part: u32,
+    pub default_input: &'static str,
+    pub description: &'static str,
+    pub solve: fn(&str) -> Result<Answer, SolverError>,
+}
+
+impl Solver {
+    pub fn get_name(&self) -> String {
+        return format!("Day {} part {}", self.day, self.part);
+    }
+
+    //Runs the solver, tagging any error with the solver's day
+    pub fn run(&self, input: &str) -> Result<Answer, SolverError> {
+        return (self.solve)(input).map_err(|x| x.with_day(self.day));
+    }
+
+    pub fn describe(&self, answer: &Answer) -> String {
+        return format!("{} {}", self.description, answer);
+    }
+}
+
+pub static SOLVERS: [Solver; 49] = [
+    Solver {
+        day: 1,
+        part: 1,
+        default_input: "src/day1_part1/input.txt",
+        description: "Sum =",
+        solve: crate::day1_part1::solve
+    },
+    Solver {
+        day: 1,
+        part: 2,
+        default_input: "src/day1_part1/input.txt",
+        description: "Sum =",
+        solve: crate::day1_part2::solve
+    },
+    Solver {
+        day: 2,
+        part: 1,
+        default_input: "src/day2_part1/input.txt",
+        description: "The sum is",
+        solve: crate::day2_part1::solve
+    },
+    Solver {
+        day: 2,
+        part: 2,
+        default_input: "src/day2_part1/input.txt",
+        description: "The sum of max powers is",
+        solve: crate::day2_part2::solve
+    },
+    Solver {
+        day: 3,
+        part: 1,
+        default_input: "src/day3_part1/input.txt",
+        description: "The sum of the engine parts is",
+        solve: crate::day3_part1::solve
+    },
+    Solver {
+        day: 3,
+        part: 2,
+        default_input: "src/day3_part1/input.txt",
+        description: "The product sum of the valid gears is",
+        solve: crate::day3_part2::solve
+    },
+    Solver {
+        day: 4,
+        part: 1,
+        default_input: "src/day4_part1/input.txt",
+        description: "The total score is",
+        solve: crate::day4_part1::solve
+    },
+    Solver {
+        day: 4,
+        part: 2,
+        default_input: "src/day4_part1/input.txt",
+        description: "The total number of cards is",
+        solve: crate::day4_part2::solve
+    },
+    Solver {
+        day: 5,
+        part: 1,
+        default_input: "src/day5_part1/input.txt",
+        description: "The min location is",
+        solve: crate::day5_part1::solve
+    },
+    Solver {
+        day: 5,
+        part: 2,
+        default_input: "src/day5_part1/input.txt",
+        description: "The min location is",
+        solve: crate::day5_part2::solve
+    },
+    Solver {
+        day: 6,
+        part: 1,
+        default_input: "src/day6_part1/input.txt",
+        description: "The winning game count product is",
+        solve: crate::day6_part1::solve
+    },
+    Solver {
+        day: 6,
+        part: 2,
+        default_input: "src/day6_part1/input.txt",
+        description: "The winning game count product is",
+        solve: crate::day6_part2::solve
+    },
+    Solver {
+        day: 7,
+        part: 1,
+        default_input: "src/day7_part1/input.txt",
+        description: "The total winnings are",
+        solve: crate::day7_part1::solve
+    },
+    Solver {
+        day: 7,
+        part: 2,
+        default_input: "src/day7_part1/input.txt",
+        description: "The total winnings are",
+        solve: crate::day7_part2::solve
+    },
+    Solver {
+        day: 8,
+        part: 1,
+        default_input: "src/day8_part1/input.txt",
+        description: "The number of map steps =",
+        solve: crate::day8_part1::solve
+    },
+    Solver {
+        day: 8,
+        part: 2,
+        default_input: "src/day8_part1/input.txt",
+        description: "The number of map steps =",
+        solve: crate::day8_part2::solve
+    },
+    Solver {
+        day: 9,
+        part: 1,
+        default_input: "src/day9_part1/input.txt",
+        description: "The sum is",
+        solve: crate::day9_part1::solve
+    },
+    Solver {
+        day: 9,
+        part: 2,
+        default_input: "src/day9_part1/input.txt",
+        description: "The sum is",
+        solve: crate::day9_part2::solve
+    },
+    Solver {
+        day: 10,
+        part: 1,
+        default_input: "src/day10_part1/input.txt",
+        description: "The maximum number of steps in the loop is",
+        solve: crate::day10_part1::solve
+    },
+    Solver {
+        day: 10,
+        part: 2,
+        default_input: "src/day10_part1/input.txt",
+        description: "The number of encircled tiles is",
+        solve: crate::day10_part2::solve
+    },
+    Solver {
+        day: 11,
+        part: 1,
+        default_input: "src/day11_part1/input.txt",
+        description: "The sum of the galaxy pair distances is",
+        solve: crate::day11_part1::solve
+    },
+    Solver {
+        day: 11,
+        part: 2,
+        default_input: "src/day11_part1/input.txt",
+        description: "The sum of the galaxy pair distances is",
+        solve: crate::day11_part2::solve
+    },
+    Solver {
+        day: 12,
+        part: 1,
+        default_input: "src/day12_part1/input.txt",
+        description: "The match sum total is",
+        solve: crate::day12_part1::solve
+    },
+    Solver {
+        day: 12,
+        part: 2,
+        default_input: "src/day12_part1/input.txt",
+        description: "The match sum total is",
+        solve: crate::day12_part2::solve
+    },
+    Solver {
+        day: 13,
+        part: 1,
+        default_input: "src/day13_part1/input.txt",
+        description: "The answer is",
+        solve: crate::day13_part1::solve
+    },
+    Solver {
+        day: 13,
+        part: 2,
+        default_input: "src/day13_part1/input.txt",
+        description: "The answer is",
+        solve: crate::day13_part2::solve
+    },
+    Solver {
+        day: 14,
+        part: 1,
+        default_input: "src/day14_part1/input.txt",
+        description: "The total load is",
+        solve: crate::day14_part1::solve
+    },
+    Solver {
+        day: 14,
+        part: 2,
+        default_input: "src/day14_part1/input.txt",
+        description: "The total load is",
+        solve: crate::day14_part2::solve
+    },
+    Solver {
+        day: 15,
+        part: 1,
+        default_input: "src/day15_part1/input.txt",
+        description: "The hash sum is",
+        solve: crate::day15_part1::solve
+    },
+    Solver {
+        day: 15,
+        part: 2,
+        default_input: "src/day15_part1/input.txt",
+        description: "The combined power is",
+        solve: crate::day15_part2::solve
+    },
+    Solver {
+        day: 16,
+        part: 1,
+        default_input: "src/day16_part1/input.txt",
+        description: "Number of energized tiles =",
+        solve: crate::day16_part1::solve
+    },
+    Solver {
+        day: 16,
+        part: 2,
+        default_input: "src/day16_part1/input.txt",
+        description: "The maximum number of energized tiles is",
+        solve: crate::day16_part2::solve
+    },
+    Solver {
+        day: 17,
+        part: 1,
+        default_input: "src/day17_part1/input.txt",
+        description: "The minimum heat loss is",
+        solve: crate::day17_part1::solve
+    },
+    Solver {
+        day: 17,
+        part: 2,
+        default_input: "src/day17_part1/input.txt",
+        description: "The minimum heat loss is",
+        solve: crate::day17_part2::solve
+    },
+    Solver {
+        day: 18,
+        part: 1,
+        default_input: "src/day18_part1/input.txt",
+        description: "Area =",
+        solve: crate::day18_part1::solve
+    },
+    Solver {
+        day: 18,
+        part: 2,
+        default_input: "src/day18_part1/input.txt",
+        description: "Area =",
+        solve: crate::day18_part2::solve
+    },
+    Solver {
+        day: 19,
+        part: 1,
+        default_input: "src/day19_part1/input.txt",
+        description: "Ratings sum =",
+        solve: crate::day19_part1::solve
+    },
+    Solver {
+        day: 19,
+        part: 2,
+        default_input: "src/day19_part1/input.txt",
+        description: "Ratings sum =",
+        solve: crate::day19_part2::solve
+    },
+    Solver {
+        day: 20,
+        part: 1,
+        default_input: "src/day20_part1/input.txt",
+        description: "Low * high pulse count =",
+        solve: crate::day20_part1::solve
+    },
+    Solver {
+        day: 20,
+        part: 2,
+        default_input: "src/day20_part1/input.txt",
+        description: "The number of button presses to activate rx is",
+        solve: crate::day20_part2::solve
+    },
+    Solver {
+        day: 21,
+        part: 1,
+        default_input: "src/day21_part1/input.txt",
+        description: "Num positions =",
+        solve: crate::day21_part1::solve
+    },
+    Solver {
+        day: 21,
+        part: 2,
+        default_input: "src/day21_part1/input.txt",
+        description: "The number of reachable garden plots is",
+        solve: crate::day21_part2::solve
+    },
+    Solver {
+        day: 22,
+        part: 1,
+        default_input: "src/day22_part1/input.txt",
+        description: "The number of bricks that can be removed is",
+        solve: crate::day22_part1::solve
+    },
+    Solver {
+        day: 22,
+        part: 2,
+        default_input: "src/day22_part1/input.txt",
+        description: "The combined bricks that would fall is",
+        solve: crate::day22_part2::solve
+    },
+    Solver {
+        day: 23,
+        part: 1,
+        default_input: "src/day23_part1/input.txt",
+        description: "The maximum number of steps is",
+        solve: crate::day23_part1::solve
+    },
+    Solver {
+        day: 23,
+        part: 2,
+        default_input: "src/day23_part1/input.txt",
+        description: "The maximum number of steps is",
+        solve: crate::day23_part2::solve
+    },
+    Solver {
+        day: 24,
+        part: 1,
+        default_input: "src/day24_part1/input.txt",
+        description: "The number of intersections is",
+        solve: crate::day24_part1::solve
+    },
+    Solver {
+        day: 24,
+        part: 2,
+        default_input: "src/day24_part1/input.txt",
+        description: "The rock starting position sum is",
+        solve: crate::day24_part2::solve
+    },
+    Solver {
+        day: 25,
+        part: 1,
+        default_input: "src/day25/input.txt",
+        description: "The group size product is",
+        solve: crate::day25::solve
+    },
+];
+
+pub fn find_solver(day: u32, part: u32) -> Option<&'static Solver> {
+    return SOLVERS.iter().find(|x| x.day == day && x.part == part);
+}
+
+pub fn find_day_solvers(day: u32) -> Vec<&'static Solver> {
+    return SOLVERS.iter().filter(|x| x.day == day).collect();
+}
@@ -0,0 +1,112 @@
+#![allow(clippy::needless_return)]
+use std::env;
+use std::process::exit;
+use std::time::Instant;
+use crate::input::InputSource;
+use crate::output::*;
+use crate::registry::*;
+
+//Reads the solver's input, exiting the process if it can't be read
+pub fn read_input(solver: &Solver, input_argument: Option<&str>) -> String {
+    let source = InputSource::resolve(solver, input_argument);
+    return match source.read() {
+        Ok(x) => x,
+        Err(error) => {
+            eprintln!("Could not read input for {} from {}: {}", solver.get_name(), source, error);
+            exit(1);
+        }
+    };
+}
+
+//Runs the solver and prints its answer, exiting the process if the input is malformed
+pub fn run_solver(solver: &Solver, input: &str, format: OutputFormat) {
+    let start = Instant::now();
+    let result = solver.run(input);
+    let elapsed = start.elapsed();
+
+    match result {
+        Ok(answer) => println!("{}", format_answer(solver, answer, elapsed, format)),
+        Err(error) => {
+            eprintln!("{} failed: {}", solver.get_name(), error);
+            exit(1);
+        }
+    }
+}
+
+//Removes "<name> <value>" from the argument list and returns the value
+pub fn take_option_value(args: &mut Vec<String>, name: &str) -> Result<Option<String>, String> {
+    let Some(index) = args.iter().position(|x| x == name) else {
+        return Ok(None);
+    };
+
+    if index + 1 >= args.len() {
+        return Err(format!("Missing value for {}", name));
+    }
+
+    let value = args.remove(index + 1);
+    args.remove(index);
+    return Ok(Some(value));
+}
+
+//take_option_value for the day binaries, which exit with their usage text if the value is missing
+pub fn take_option(args: &mut Vec<String>, name: &str, usage: &str) -> Option<String> {
+    return take_option_value(args, name).unwrap_or_else(|x| exit_with_usage(&x, usage));
+}
+
+//Removes the flag from the argument list and returns whether it was there
+pub fn take_flag(args: &mut Vec<String>, name: &str) -> bool {
+    let Some(index) = args.iter().position(|x| x == name) else {
+        return false;
+    };
+
+    args.remove(index);
+    return true;
+}
+
+//Reads the input given by the only argument left once the options are taken, or the solver's default input if
+//there's none. Fails if more than one argument is left.
+pub fn read_positional_input(solver: &Solver, args: &[String]) -> Result<String, String> {
+    return match args {
+        [] => Ok(read_input(solver, None)),
+        [input] => Ok(read_input(solver, Some(input))),
+        _ => Err(String::from("Invalid arguments"))
+    };
+}
+
+//For the day binaries when the input or query can't be handled
+pub fn exit_with_error(day: u32, message: &str) -> ! {
+    eprintln!("Day {} failed: {}", day, message);
+    exit(1);
+}
+
+//For the binaries when the arguments are wrong
+pub fn exit_with_usage(message: &str, usage: &str) -> ! {
+    eprintln!("{}\n\n{}", message, usage);
+    exit(2);
+}
+
+//Removes "--format <format>" from the argument list, defaulting to text
+pub fn take_output_format(args: &mut Vec<String>) -> Result<OutputFormat, String> {
+    return match take_option_value(args, FORMAT_OPTION)? {
+        Some(format) => format.parse::<OutputFormat>(),
+        None => Ok(OutputFormat::default())
+    };
+}
+
+//Shared main() for the single solver binaries. The only accepted arguments are an optional input path and
+//the output format.
+pub fn run_bin(day: u32, part: u32) {
+    let solver = find_solver(day, part).unwrap();
+    let mut args = env::args().skip(1).collect::<Vec<String>>();
+    let format = match take_output_format(&mut args) {
+        Ok(x) => x,
+        Err(message) => exit_with_bin_usage(&message)
+    };
+
+    let input = read_positional_input(solver, &args).unwrap_or_else(|x| exit_with_bin_usage(&x));
+    run_solver(solver, &input, format);
+}
+
+fn exit_with_bin_usage(message: &str) -> ! {
+    exit_with_usage(message, &format!("Usage: {} [input path | -] [--format text|json|plain]", env::args().next().unwrap_or_default()));
+}
@@ -0,0 +1,112 @@
+#![allow(clippy::needless_return)]
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::answer::Answer;
+use crate::input::{InputSource, INPUT_DIR_VARIABLE};
+use crate::registry::Solver;
+
+pub const ANSWERS_FILE_NAME: &str = "answers.toml";
+
+//Accepted answers for one set of puzzle inputs. The file has a table per day with a key per part:
+//
+//  [day17]
+//  part1 = 902
+//  part2 = 1073
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AnswerKey {
+    answers: HashMap<(u32, u32), i64>
+}
+
+impl AnswerKey {
+    //The answers sit next to the inputs they belong to, so use AOC_INPUT_DIR if it's set
+    pub fn default_path() -> PathBuf {
+        return match env::var_os(INPUT_DIR_VARIABLE) {
+            Some(input_dir) => PathBuf::from(input_dir).join(ANSWERS_FILE_NAME),
+            None => PathBuf::from(ANSWERS_FILE_NAME)
+        };
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path).map_err(|x| x.to_string())?;
+        return AnswerKey::parse(&contents);
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let tables = toml::from_str::<BTreeMap<String, BTreeMap<String, i64>>>(contents).map_err(|x| x.to_string())?;
+
+        let mut answers = HashMap::<(u32, u32), i64>::new();
+        for (day_key, parts) in tables {
+            let day = parse_key(&day_key, "day")?;
+            for (part_key, value) in parts {
+                answers.insert((day, parse_key(&part_key, "part")?), value);
+            }
+        }
+
+        return Ok(AnswerKey { answers });
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<i64> {
+        return self.answers.get(&(day, part)).copied();
+    }
+}
+
+//Parses keys such as "day17" or "part2"
+fn parse_key(key: &str, prefix: &str) -> Result<u32, String> {
+    return key
+        .strip_prefix(prefix)
+        .and_then(|x| x.parse::<u32>().ok())
+        .ok_or_else(|| format!("Invalid key '{}', expected {}<number>", key, prefix));
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: i64, actual: i64 },
+    //The solver ran but there is no accepted answer to check against
+    Missing { actual: i64 },
+    //The input couldn't be read or the solver rejected it
+    Error(String)
+}
+
+impl Verdict {
+    pub fn is_failure(&self) -> bool {
+        return matches!(self, Verdict::Fail { .. } | Verdict::Error(_));
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { expected, actual } => write!(f, "FAIL    expected {}, got {} (difference {})",
+                Answer(*expected), Answer(*actual), Answer(actual - expected)),
+            Verdict::Missing { actual } => write!(f, "MISSING got {}", Answer(*actual)),
+            Verdict::Error(message) => write!(f, "ERROR   {}", message)
+        };
+    }
+}
+
+pub fn verify_answer(solver: &Solver, input: &str, answer_key: &AnswerKey) -> Verdict {
+    let actual = match solver.run(input) {
+        Ok(x) => x.get_value(),
+        Err(error) => return Verdict::Error(error.to_string())
+    };
+
+    return match answer_key.get(solver.day, solver.part) {
+        Some(expected) if expected == actual => Verdict::Pass,
+        Some(expected) => Verdict::Fail { expected, actual },
+        None => Verdict::Missing { actual }
+    };
+}
+
+//Reads the solver's usual input, reporting a read failure as an error verdict rather than exiting
+pub fn verify_solver(solver: &Solver, answer_key: &AnswerKey) -> Verdict {
+    let source = InputSource::resolve(solver, None);
+    return match source.read() {
+        Ok(input) => verify_answer(solver, &input, answer_key),
+        Err(error) => Verdict::Error(format!("Could not read input from {}: {}", source, error))
+    };
+}
@@ -0,0 +1,92 @@
+//Checks the day 5 almanac parser and the queries that walk its chain of maps
+#![allow(clippy::needless_return)]
+mod common;
+use common::read_fixture;
+use sandbox::day5::*;
+use sandbox::error::SolverError;
+use sandbox::range_set::RangeSet;
+
+fn read_example() -> Almanac {
+    return Almanac::parse(&read_fixture("day5.txt")).unwrap();
+}
+
+//Maps listed out of order, with an extra category spliced in and a branch that doesn't lead to location
+const REORDERED: &str = "\
+seeds: 10 5 90 5
+
+soil-to-location map:
+100 0 50
+
+seed-to-soil map:
+20 10 5
+
+seed-to-weather map:
+0 0 1000
+
+weather-to-soil map:
+0 0 10
+";
+
+#[test]
+fn example_queries() {
+    let almanac = read_example();
+    assert_eq!(almanac.get_seeds(), [79, 14, 55, 13]);
+    assert_eq!(almanac.get_maps().len(), 7);
+    assert_eq!(almanac.find_chain("seed", "humidity").unwrap().join(" -> "),
+        "seed -> soil -> fertilizer -> water -> light -> temperature -> humidity");
+    assert_eq!(almanac.map_value("seed", "humidity", 79).unwrap(), 78);
+    assert_eq!(almanac.map_value("water", "light", 81).unwrap(), 74);
+    assert_eq!(almanac.map_value("soil", "soil", 81).unwrap(), 81);
+
+    let location_ranges = almanac.map_ranges(SEED_CATEGORY, LOCATION_CATEGORY, &almanac.get_seed_ranges().unwrap()).unwrap();
+    assert_eq!(location_ranges.get_min(), Some(46));
+}
+
+#[test]
+fn reordered_and_extra_categories() {
+    let almanac = Almanac::parse(REORDERED).unwrap();
+    assert_eq!(almanac.find_chain("seed", "location").unwrap(), ["seed", "soil", "location"]);
+    assert_eq!(almanac.find_chain("weather", "location").unwrap(), ["weather", "soil", "location"]);
+    assert_eq!(almanac.map_value("seed", "location", 12).unwrap(), 122);
+    assert_eq!(almanac.map_value("seed", "location", 90).unwrap(), 90);
+
+    let seeds = almanac.get_seed_ranges().unwrap();
+    assert_eq!(seeds, [10..15, 90..95].into_iter().collect::<RangeSet>());
+    assert_eq!(almanac.map_ranges("seed", "location", &seeds).unwrap().get_ranges(), [90..95, 120..125]);
+
+    assert_eq!(almanac.find_chain("location", "seed").unwrap_err(),
+        SolverError::invalid_input("There is no chain of maps from location to seed"));
+}
+
+#[test]
+fn reverse_queries() {
+    let almanac = read_example();
+    let seed_ranges = almanac.get_seed_ranges().unwrap();
+
+    //The part 2 answer checked from the location end
+    let reaching_46 = almanac.reverse_map_ranges(SEED_CATEGORY, LOCATION_CATEGORY, &RangeSet::from(0..47)).unwrap();
+    assert_eq!(reaching_46.intersection(&seed_ranges), RangeSet::from(82..83));
+    let below_46 = almanac.reverse_map_ranges(SEED_CATEGORY, LOCATION_CATEGORY, &RangeSet::from(0..46)).unwrap();
+    assert!(below_46.intersection(&seed_ranges).is_empty());
+
+    let location_seed = almanac.get_inverse_map(SEED_CATEGORY, LOCATION_CATEGORY).unwrap();
+    assert_eq!([82, 43, 86, 35].map(|x| location_seed.map_value(x)), [79, 14, 55, 13]);
+    assert_eq!(almanac.reverse_map_ranges("humidity", "location", &RangeSet::from(60..61)).unwrap(), RangeSet::from(56..57));
+}
+
+#[test]
+fn malformed_almanacs() {
+    let duplicate = Almanac::parse("seeds: 1\n\na-to-b map:\n1 2 3\n\na-to-b map:\n4 5 6\n").unwrap_err();
+    assert_eq!(duplicate.to_string(), "Duplicate map at line 6, column 1: 'a-to-b map:'");
+
+    let orphan = Almanac::parse("seeds: 1\n\n1 2 3\n").unwrap_err();
+    assert!(matches!(orphan, SolverError::Parse { line: 3, .. }));
+
+    let bad_header = Almanac::parse("seeds: 1\n\nseed map:\n").unwrap_err();
+    assert!(matches!(bad_header, SolverError::Parse { line: 3, .. }));
+
+    let overlap = Almanac::parse("seeds: 1\n\na-to-b map:\n0 10 5\n0 12 5\n").unwrap_err();
+    assert!(matches!(overlap, SolverError::Parse { line: 5, .. }));
+
+    assert!(Almanac::parse("seeds: 1 2 3\n").unwrap().get_seed_ranges().is_err());
+}
@@ -0,0 +1,55 @@
+//Checks the benchmark statistics and result files
+#![allow(clippy::needless_return)]
+use std::env;
+use std::fs;
+use std::time::Duration;
+use sandbox::bench::*;
+use sandbox::registry::find_solver;
+
+fn sample_result(day: u32) -> BenchResult {
+    let statistics = Statistics { min_ms: 1.25, median_ms: 2.5, p95_ms: 10.0 };
+    return BenchResult { day, part: 2, iterations: 20, parse: statistics, solve: statistics, total: statistics };
+}
+
+#[test]
+fn statistics() {
+    let durations = (1..=20).rev().map(Duration::from_millis).collect::<Vec<Duration>>();
+    let statistics = Statistics::from_durations(&durations);
+    assert_eq!(statistics, Statistics { min_ms: 1.0, median_ms: 10.0, p95_ms: 19.0 });
+
+    let single = Statistics::from_durations(&[Duration::from_millis(3)]);
+    assert_eq!(single, Statistics { min_ms: 3.0, median_ms: 3.0, p95_ms: 3.0 });
+}
+
+#[test]
+fn phases_add_up() {
+    let solver = find_solver(9, 1).unwrap();
+    let (answer, times) = time_solver(solver, "0 3 6 9 12 15\n").unwrap();
+    assert_eq!(answer.get_value(), 18);
+    assert!(times.parse > Duration::ZERO);
+    assert_eq!(times.get_total(), times.parse + times.solve);
+}
+
+#[test]
+fn result_files_round_trip() {
+    let results = vec![sample_result(7), sample_result(17)];
+    for extension in ["csv", "json"] {
+        let path = env::temp_dir().join(format!("aoc_bench_round_trip_{}.{}", std::process::id(), extension));
+        write_results(&path, &results).unwrap();
+        let read_back = read_results(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(read_back.unwrap(), results);
+    }
+}
+
+#[test]
+fn baseline_comparison() {
+    let result = sample_result(7);
+    let mut baseline = sample_result(7);
+    baseline.total.median_ms = 2.0;
+    assert!(format_bench_result(&result, Some(&baseline)).ends_with("  (+25.0% median vs baseline)"));
+    assert!(!format_bench_result(&result, None).contains("baseline"));
+
+    baseline.total.median_ms = 0.0;
+    assert!(format_bench_result(&result, Some(&baseline)).ends_with("  (n/a median vs baseline)"));
+}
@@ -0,0 +1,160 @@
+//Checks the day 7 hand scoring under each rule set
+#![allow(clippy::needless_return)]
+mod common;
+use common::read_fixture;
+use sandbox::day7::*;
+use sandbox::day7::hand::*;
+use sandbox::day7::hand::HandType::*;
+use sandbox::day7::report::HandReport;
+use sandbox::day7::rules::*;
+use itertools::Itertools;
+use rayon::prelude::*;
+
+fn hand(labels: &str, rules: &dyn RuleSet) -> Hand {
+    return parse_data(&format!("{} 1", labels), rules).unwrap().remove(0);
+}
+
+#[test]
+fn hand_types() {
+    assert_eq!(*hand("KTJJT", &StandardRules).get_hand_type(), TwoPair);
+    assert_eq!(*hand("KTJJT", &JokerRules).get_hand_type(), FourOfAKind);
+    assert_eq!(*hand("JJJJJ", &JokerRules).get_hand_type(), FiveOfAKind);
+    assert_eq!(*hand("2345J", &JokerRules).get_hand_type(), OnePair);
+    assert_eq!(*hand("2233J", &JokerRules).get_hand_type(), FullHouse);
+    assert_eq!(hand("QQQJA", &JokerRules).get_cards(), &[Card::Queen, Card::Queen, Card::Queen, Card::Jack, Card::Ace]);
+}
+
+#[test]
+fn tie_breaks_follow_card_strength() {
+    assert!(hand("JKKK2", &StandardRules) < hand("QKKK2", &StandardRules));
+    assert!(hand("JKKK2", &JokerRules) > hand("QKKK2", &JokerRules));
+    assert!(hand("JKKK2", &JokerRules) < hand("QQQQ2", &JokerRules));
+    assert!(hand("T55J5", &JokerRules) < hand("QQQJA", &JokerRules));
+    assert!(hand("KK677", &StandardRules) > hand("KTJJT", &StandardRules));
+}
+
+#[test]
+fn custom_rules() {
+    let example = read_fixture("day7.txt");
+    let jokers_as_custom = CustomRules::parse("J23456789TQKA", "J").unwrap();
+    assert_eq!(solve_with_rules(&example, &jokers_as_custom).unwrap().get_value(), 5905);
+    assert_eq!(solve_with_rules(&example, &CustomRules::parse("23456789TJQKA", "").unwrap()).unwrap().get_value(), 6440);
+
+    //Twos wild and aces low
+    let twos_wild = CustomRules::parse("A23456789TJQK", "2").unwrap();
+    assert_eq!(*hand("2KK34", &twos_wild).get_hand_type(), ThreeOfAKind);
+    assert!(hand("A3456", &twos_wild) < hand("23456", &twos_wild));
+
+    assert_eq!(CustomRules::parse("23456789TJQK", ""), Err(String::from("The card order is missing 'A'")));
+    assert_eq!(CustomRules::parse("23456789TJQKAA", ""), Err(String::from("The card order lists a card more than once")));
+    assert_eq!(CustomRules::parse("23456789TJQKA", "X"), Err(String::from("Invalid card 'X'")));
+}
+
+#[test]
+fn winnings_use_sorted_ranks() {
+    let mut hands = parse_data(&read_fixture("day7.txt"), &JokerRules).unwrap();
+    hands.sort();
+    assert_eq!(hands.iter().map(|x| *x.get_bid()).collect::<Vec<u32>>(), [765, 28, 684, 483, 220]);
+    assert_eq!(get_total_winnings(&hands), 5905);
+}
+
+//Reference classifier that only handles hands without wild cards
+fn classify_without_wild_cards(cards: &[Card]) -> HandType {
+    let mut card_counts = [0usize; 13];
+    for card in cards {
+        card_counts[*card as usize] += 1;
+    }
+
+    let sorted_counts = card_counts.into_iter().filter(|x| *x > 0).sorted().rev().collect::<Vec<usize>>();
+    return match sorted_counts.as_slice() {
+        [5] => FiveOfAKind,
+        [4, 1] => FourOfAKind,
+        [3, 2] => FullHouse,
+        [3, 1, 1] => ThreeOfAKind,
+        [2, 2, 1] => TwoPair,
+        [2, 1, 1, 1] => OnePair,
+        _ => HighCard
+    };
+}
+
+//Tries every substitution of the wild cards. Substituting a card the hand doesn't already hold never helps,
+//so only those cards are tried, plus an ace for hands that are all wild.
+fn classify_by_brute_force(cards: &[Card], rules: &dyn RuleSet) -> HandType {
+    let mut choices = cards.iter().filter(|x| !rules.is_wild(**x)).unique().copied().collect::<Vec<Card>>();
+    if choices.is_empty() {
+        choices.push(Card::Ace);
+    }
+
+    let wild_positions = (0..cards.len()).filter(|x| rules.is_wild(cards[*x])).collect::<Vec<usize>>();
+    if wild_positions.is_empty() {
+        return classify_without_wild_cards(cards);
+    }
+
+    return wild_positions
+        .iter()
+        .map(|_| choices.iter())
+        .multi_cartesian_product()
+        .map(|substitution| {
+            let mut test_hand = cards.to_vec();
+            for (position, card) in wild_positions.iter().zip(substitution) {
+                test_hand[*position] = *card;
+            }
+            classify_without_wild_cards(&test_hand)
+        })
+        .max()
+        .unwrap();
+}
+
+#[test]
+fn classifier_matches_brute_force_for_every_hand() {
+    let two_wild_ranks = CustomRules::parse("23456789TJQKA", "J2").unwrap();
+    let rule_sets: [&(dyn RuleSet + Sync); 3] = [&StandardRules, &JokerRules, &two_wild_ranks];
+    //Split the 13^5 hands up by their first card to check them in parallel
+    Card::ALL.par_iter().for_each(|first_card| {
+        for other_cards in (0..4).map(|_| Card::ALL.iter().copied()).multi_cartesian_product() {
+            let cards = [vec![*first_card], other_cards].concat();
+            for rules in rule_sets {
+                assert_eq!(Hand::calculate_hand_type(&cards, rules), classify_by_brute_force(&cards, rules), "hand {:?}", cards);
+            }
+        }
+    });
+}
+
+#[test]
+fn hands_with_more_than_five_cards() {
+    let cards = |labels: &str| labels.chars().map(|x| Card::from_label(x).unwrap()).collect::<Vec<Card>>();
+    assert_eq!(Hand::calculate_hand_type(&cards("2233344"), &StandardRules), FullHouse);
+    assert_eq!(Hand::calculate_hand_type(&cards("2233445"), &StandardRules), TwoPair);
+    assert_eq!(Hand::calculate_hand_type(&cards("23456789"), &StandardRules), HighCard);
+    assert_eq!(Hand::calculate_hand_type(&cards("22334JJ"), &JokerRules), FourOfAKind);
+    assert_eq!(Hand::calculate_hand_type(&cards("2345678JJJ"), &JokerRules), FourOfAKind);
+    assert_eq!(Hand::calculate_hand_type(&cards("222JJJJ"), &JokerRules), FiveOfAKind);
+}
+
+#[test]
+fn hand_report() {
+    let mut hands = parse_data(&read_fixture("day7.txt"), &JokerRules).unwrap();
+    hands.sort();
+    let report = HandReport::new(&hands, 2);
+    assert_eq!(report.get_hand_count(), 5);
+    assert_eq!(report.get_total_winnings(), 5905);
+
+    let four_of_a_kind = report.get_type_summary(FourOfAKind).unwrap();
+    assert_eq!((four_of_a_kind.get_count(), four_of_a_kind.get_lowest_rank(), four_of_a_kind.get_highest_rank()), (3, 3, 5));
+    assert_eq!(four_of_a_kind.get_total_bid(), 684 + 483 + 220);
+    assert_eq!(report.get_type_summary(OnePair).unwrap().get_winnings(), 765);
+    assert!(report.get_type_summary(FullHouse).is_none());
+
+    assert_eq!(report.get_upgrade_count(), 3);
+    assert_eq!(report.get_upgrades().get(&(ThreeOfAKind, FourOfAKind)), Some(&2));
+    assert_eq!(report.get_upgrades().get(&(TwoPair, FourOfAKind)), Some(&1));
+    assert_eq!((report.get_min_bid(), report.get_median_bid(), report.get_max_bid()), (Some(28), Some(483), Some(765)));
+
+    let top_hands = report.get_top_hands().iter().map(|x| (x.get_rank(), *x.get_hand().get_bid())).collect::<Vec<(usize, u32)>>();
+    assert_eq!(top_hands, [(5, 220), (4, 483)]);
+    assert_eq!(report.get_top_hands()[0].get_winnings(), 1100);
+
+    //No wild cards means no upgrades
+    assert_eq!(HandReport::new(&parse_data(&read_fixture("day7.txt"), &StandardRules).unwrap(), 0).get_upgrade_count(), 0);
+    assert_eq!(HandReport::new(&[], 5).get_median_bid(), None);
+}
@@ -0,0 +1,15 @@
+//Helpers shared by the integration tests. Each test crate only uses some of them.
+#![allow(dead_code, clippy::needless_return)]
+use std::fs;
+use std::path::{Path, PathBuf};
+
+//Path of a file relative to the crate root, e.g. the answers file
+pub fn crate_path(name: &str) -> PathBuf {
+    return Path::new(env!("CARGO_MANIFEST_DIR")).join(name);
+}
+
+//Reads one of the example inputs in tests/fixtures
+pub fn read_fixture(name: &str) -> String {
+    let path = crate_path("tests/fixtures").join(name);
+    return fs::read_to_string(&path).unwrap_or_else(|e| panic!("Could not read {}: {}", path.display(), e));
+}
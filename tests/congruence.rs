@@ -0,0 +1,58 @@
+//Checks the generalized Chinese remainder theorem solver
+use sandbox::congruence::*;
+
+#[test]
+fn coprime_moduli() {
+    let combined = Congruence::new(2, 3).combine(&Congruence::new(3, 5)).unwrap().unwrap();
+    assert_eq!((combined.get_residue(), combined.get_modulus()), (8, 15));
+
+    let all = solve_congruences(&[Congruence::new(2, 3), Congruence::new(3, 5), Congruence::new(2, 7)]).unwrap().unwrap();
+    assert_eq!(all, Congruence::new(23, 105));
+    assert!(all.contains(128));
+    assert!(!all.contains(129));
+}
+
+#[test]
+fn shared_factors() {
+    assert_eq!(Congruence::new(3, 6).combine(&Congruence::new(7, 10)), Ok(Some(Congruence::new(27, 30))));
+    assert_eq!(Congruence::new(1, 4).combine(&Congruence::new(2, 6)), Ok(None));
+    assert_eq!(Congruence::new(5, 12).combine(&Congruence::new(17, 24)), Ok(Some(Congruence::new(17, 24))));
+
+    //Same period but different phases never line up, which is how ghosts can fail to meet
+    assert_eq!(solve_congruences(&[Congruence::new(0, 2), Congruence::new(1, 2)]), Ok(None));
+    assert_eq!(solve_congruences(&[]), Ok(Some(Congruence::new(0, 1))));
+}
+
+#[test]
+fn large_moduli() {
+    let first = Congruence::new(3_000_000_000, 4_000_000_007);
+    let second = Congruence::new(5, 1_000_000_007);
+    let combined = first.combine(&second).unwrap().unwrap();
+    assert_eq!(combined.get_modulus(), 4_000_000_007 * 1_000_000_007);
+    assert!(first.contains(combined.get_residue()) && second.contains(combined.get_residue()));
+
+    //Inverting a modulus of one against a large one used to overflow an i128 product
+    let large = Congruence::new(u64::MAX - 2, u64::MAX);
+    assert_eq!(Congruence::new(0, 1).combine(&large), Ok(Some(large)));
+    let (small, near_limit) = (Congruence::new(3, 5), Congruence::new(u64::MAX / 8 - 1, u64::MAX / 8));
+    let combined = small.combine(&near_limit).unwrap().unwrap();
+    assert_eq!(combined.get_modulus(), u64::MAX / 8 * 5);
+    assert!(small.contains(combined.get_residue()) && near_limit.contains(combined.get_residue()));
+}
+
+#[test]
+fn lcm_overflow() {
+    let primes = [1009, 1013, 1019, 1021, 1031, 1033, 1039].map(|x| Congruence::new(1, x));
+    assert!(solve_congruences(&primes[..6]).unwrap().is_some());
+    assert!(solve_congruences(&primes).is_err());
+    assert!(Congruence::new(0, u64::MAX).combine(&Congruence::new(0, 2)).is_err());
+}
+
+#[test]
+fn first_at_least() {
+    let congruence = Congruence::new(8, 15);
+    assert_eq!(congruence.first_at_least(0), 8);
+    assert_eq!(congruence.first_at_least(8), 8);
+    assert_eq!(congruence.first_at_least(9), 23);
+    assert_eq!(Congruence::new(17, 5).get_residue(), 2);
+}
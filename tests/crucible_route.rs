@@ -0,0 +1,84 @@
+//Checks the day 17 crucible rules, the route reconstruction, and its renderings
+#![allow(clippy::needless_return)]
+mod common;
+use common::read_fixture;
+use sandbox::day17::*;
+use sandbox::day17::route::*;
+use sandbox::direction::Direction::*;
+use sandbox::error::SolverError;
+use sandbox::grid::Grid;
+use sandbox::registry::find_solver;
+
+fn read_fixture_map(name: &str) -> Grid<usize> {
+    return parse_data(&read_fixture(name)).unwrap();
+}
+
+#[test]
+fn route_cost_matches_heat_loss() {
+    let heat_loss_reference = read_fixture_map("day17.txt");
+    let min_route = find_minimum_heat_loss(&heat_loss_reference, &CrucibleRules::ultra()).unwrap();
+    let route = min_route.get_path();
+
+    assert_eq!((route[0].row_index, route[0].col_index), (0, 0));
+    assert_eq!((min_route.get_goal().row_index, min_route.get_goal().col_index), (12, 12));
+    assert_eq!(route[1..].iter().map(|x| heat_loss_reference[(x.row_index, x.col_index)]).sum::<usize>(), min_route.get_cost());
+    assert_eq!(min_route.get_cost(), 94);
+}
+
+#[test]
+fn custom_rules() {
+    let heat_loss_reference = read_fixture_map("day17.txt");
+    let cost = |rules: CrucibleRules| find_minimum_heat_loss(&heat_loss_reference, &rules).unwrap().get_cost();
+    assert_eq!(cost(CrucibleRules::normal()), 102);
+
+    //Without a straight line limit the cheapest route is the plain shortest path
+    let unlimited = CrucibleRules { max_straight: 13, ..CrucibleRules::normal() };
+    assert!(cost(unlimited.clone()) <= 102);
+    assert_eq!(cost(CrucibleRules { allow_reverse: true, ..unlimited.clone() }), cost(unlimited));
+
+    //Starting south only can't be cheaper than also being allowed to start east
+    assert!(cost(CrucibleRules { start_directions: vec![South], ..CrucibleRules::normal() }) >= 102);
+
+    //The ultra crucible can't stop in the corner of a map too small to build up speed
+    let small_map = parse_data("123\n456\n").unwrap();
+    assert_eq!(find_minimum_heat_loss(&small_map, &CrucibleRules::ultra()).unwrap_err(),
+        SolverError::invalid_input("There is no path to the bottom right corner"));
+
+    //On a single block map the crucible starts on the goal, whatever its rules
+    let single_block = parse_data("7\n").unwrap();
+    for rules in [CrucibleRules::normal(), CrucibleRules::ultra()] {
+        let min_route = find_minimum_heat_loss(&single_block, &rules).unwrap();
+        assert_eq!((min_route.get_cost(), min_route.get_path().len()), (0, 1));
+    }
+    assert_eq!(find_solver(17, 2).unwrap().run("7\n").unwrap().get_value(), 0);
+
+    let invalid = CrucibleRules { min_straight: 5, max_straight: 4, ..CrucibleRules::normal() };
+    assert!(find_minimum_heat_loss(&heat_loss_reference, &invalid).is_err());
+}
+
+#[test]
+fn ascii_overlay() {
+    let heat_loss_reference = read_fixture_map("day17_part2_unlucky.txt");
+    let min_route = find_minimum_heat_loss(&heat_loss_reference, &CrucibleRules::ultra()).unwrap();
+    assert_eq!(render_ascii(&heat_loss_reference, min_route.get_path()), "\
+1>>>>>>>1111
+9999999v9991
+9999999v9991
+9999999v9991
+9999999v>>>>
+");
+}
+
+#[test]
+fn svg_heat_map() {
+    let heat_loss_reference = read_fixture_map("day17_part2_unlucky.txt");
+    let min_route = find_minimum_heat_loss(&heat_loss_reference, &CrucibleRules::ultra()).unwrap();
+    let svg = render_svg(&heat_loss_reference, min_route.get_path());
+
+    assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="120" height="50""#));
+    assert_eq!(svg.matches("<rect ").count(), 60);
+    assert!(svg.contains(r#"fill="rgb(255,237,160)"><title>1</title>"#));
+    assert!(svg.contains(r#"fill="rgb(189,0,38)"><title>9</title>"#));
+    assert!(svg.contains(r#"<polyline points="5,5 15,5 "#));
+    assert!(svg.trim_end().ends_with("</svg>"));
+}
@@ -0,0 +1,122 @@
+//Checks the day 8 network parser, export, analysis and jump table against the part 2 example
+#![allow(clippy::needless_return)]
+mod common;
+use common::read_fixture;
+use sandbox::day8::*;
+use sandbox::day8::jump_table::JumpTable;
+use sandbox::day8_part2::scalar_solver_iterator::ScalarSolverIterator;
+use sandbox::error::SolverError;
+
+fn read_example() -> DesertNetwork {
+    return DesertNetwork::parse(&read_fixture("day8_part2.txt")).unwrap();
+}
+
+fn names<'a>(network: &'a DesertNetwork, nodes: &[NodeId]) -> Vec<&'a str> {
+    return nodes.iter().map(|x| network.get_name(*x)).collect();
+}
+
+#[test]
+fn interned_nodes() {
+    let network = read_example();
+    assert_eq!(network.get_node_count(), 8);
+    assert_eq!(network.get_id("11A"), Some(0));
+    assert_eq!(network.get_id("XXX"), Some(7));
+    assert_eq!(network.get_id("33A"), None);
+    assert_eq!(names(&network, &network.get_successors(0)), ["11B", "XXX"]);
+    assert_eq!(names(&network, &network.get_start_nodes()), ["11A", "22A"]);
+    assert!(network.is_end(network.get_id("22Z").unwrap()));
+    assert_eq!(network.get_name(network.walk(0, 3)), "11B");
+}
+
+#[test]
+fn malformed_networks() {
+    let duplicate = DesertNetwork::parse("L\n\nAAA = (AAA, AAA)\nAAA = (AAA, AAA)\n").unwrap_err();
+    assert_eq!(duplicate, SolverError::parse_token(3, "AAA = (AAA, AAA)", "AAA", "Duplicate node"));
+
+    let unknown = DesertNetwork::parse("L\n\nAAA = (BBB, CCC)\nBBB = (AAA, AAA)\n").unwrap_err();
+    assert_eq!(unknown, SolverError::parse_token(2, "AAA = (BBB, CCC)", "CCC", "Step leads to an unknown node"));
+
+    assert!(matches!(DesertNetwork::parse("LX\n\nAAA = (AAA, AAA)\n").unwrap_err(), SolverError::Parse { line: 1, column: 2, .. }));
+    assert!(matches!(DesertNetwork::parse("L\n\nAAA = AAA, AAA\n").unwrap_err(), SolverError::Parse { line: 3, .. }));
+}
+
+#[test]
+fn strongly_connected_components() {
+    let network = read_example();
+    let components = network.find_strongly_connected_components();
+    let component_names = components.iter().map(|x| names(&network, x)).collect::<Vec<Vec<&str>>>();
+    assert_eq!(component_names, [vec!["22B", "22C", "22Z"], vec!["11B", "11Z"], vec!["11A"], vec!["22A"], vec!["XXX"]]);
+    assert_eq!(components.iter().map(|x| network.is_cyclic(x)).collect::<Vec<bool>>(), [true, true, false, false, true]);
+}
+
+#[test]
+fn reachable_ends() {
+    let network = read_example();
+    let reachable = network
+        .find_reachable_ends()
+        .iter()
+        .map(|(start, ends)| (network.get_name(*start), names(&network, ends)))
+        .collect::<Vec<(&str, Vec<&str>)>>();
+    assert_eq!(reachable, [("11A", vec!["11Z"]), ("22A", vec!["22Z"])]);
+
+    //Ends behind a dead end loop aren't reachable
+    let network = DesertNetwork::parse("L\n\nAAA = (XXX, XXX)\nXXX = (XXX, XXX)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+    assert!(network.find_reachable_ends()[0].1.is_empty());
+}
+
+#[test]
+fn exports() {
+    let network = read_example();
+
+    let dot = network.to_dot();
+    assert!(dot.starts_with("digraph desert {\n"));
+    assert!(dot.contains("    \"11A\" [style=filled, fillcolor=palegreen];\n"));
+    assert!(dot.contains("    \"22Z\" [style=filled, fillcolor=salmon];\n"));
+    assert!(dot.contains("    \"11A\" -> \"11B\" [label=\"L\"];\n    \"11A\" -> \"XXX\" [label=\"R\"];\n"));
+    assert!(dot.contains("    \"XXX\" -> \"XXX\" [label=\"L,R\"];\n"));
+
+    let json = serde_json::from_str::<serde_json::Value>(&network.to_json()).unwrap();
+    assert_eq!(json["instructions"], "LR");
+    assert_eq!(json["nodes"].as_array().unwrap().len(), 8);
+    assert_eq!(json["nodes"][0], serde_json::json!({ "name": "11A", "left": "11B", "right": "XXX", "start": true, "end": false }));
+}
+
+#[test]
+fn cycle_structure() {
+    let network = read_example();
+    let first = ScalarSolverIterator::new(network.get_id("11A").unwrap(), &network).detect_cycle();
+    assert_eq!((first.get_prefix_length(), first.get_cycle_length(), first.get_cycle_hits()), (1, 2, [2].as_slice()));
+    assert!(first.is_aligned());
+
+    //Hits on steps 3 and 6 of a six step cycle, which the LCM shortcut can't handle
+    let second = ScalarSolverIterator::new(network.get_id("22A").unwrap(), &network).detect_cycle();
+    assert_eq!((second.get_prefix_length(), second.get_cycle_length(), second.get_cycle_hits()), (1, 6, [3, 6].as_slice()));
+    assert!(!second.is_aligned());
+}
+
+#[test]
+fn jump_table_matches_stepping() {
+    let network = DesertNetwork::parse("LRRLR\n\nAAA = (BBB, CCC)\nBBB = (DDD, AAA)\nCCC = (BBB, DDD)\nDDD = (CCC, AAA)\n").unwrap();
+    let jump_table = JumpTable::new(&network, 200);
+    assert!(jump_table.get_max_steps() >= 200);
+    for start in 0..network.get_node_count() as NodeId {
+        for num_steps in 0..=200 {
+            assert_eq!(jump_table.walk(start, num_steps), network.walk(start, num_steps), "{} steps from {}", num_steps, start);
+        }
+    }
+}
+
+#[test]
+fn jump_table_long_walks() {
+    //Every walk in the example repeats within seven steps, so far walks can be checked against short ones
+    let network = read_example();
+    let jump_table = JumpTable::new(&network, u64::MAX);
+    assert_eq!(jump_table.get_max_steps(), u64::MAX);
+    let start = network.get_id("22A").unwrap();
+    for num_steps in [1_000_000_000_000u64, 1_000_000_000_001, u64::MAX] {
+        assert_eq!(jump_table.walk(start, num_steps), network.walk(start, 1 + (num_steps - 1) % 6));
+    }
+
+    //Part 2 ends on a step where every ghost is on an end node
+    assert!(network.get_start_nodes().iter().all(|x| network.is_end(jump_table.walk(*x, 6))));
+}
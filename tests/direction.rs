@@ -0,0 +1,71 @@
+//Checks the shared directions used by the map based days
+use sandbox::direction::*;
+
+#[test]
+fn turns() {
+    for direction in Direction::ALL {
+        assert_eq!(direction.turn_left().turn_right(), direction);
+        assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+        assert_eq!(direction.reverse().reverse(), direction);
+    }
+    assert_eq!(Direction::North.turn_right(), Direction::East);
+    assert_eq!(Direction::North.turn_left(), Direction::West);
+    assert!(Direction::South.is_vertical());
+    assert!(!Direction::West.is_vertical());
+}
+
+#[test]
+fn parse_letters() {
+    let parsed = "URDL".chars().map(Direction::from_letter).collect::<Vec<Option<Direction>>>();
+    assert_eq!(parsed, Direction::ALL.map(Some));
+    assert_eq!(Direction::from_letter('X'), None);
+}
+
+#[test]
+fn movement() {
+    assert_eq!(Direction::North.get_delta(), (-1, 0));
+    assert_eq!(Direction::East.advance((0, 0)), (0, 1));
+    assert_eq!(Direction::West.advance((0, 0)), (0, -1));
+    assert_eq!(Direction::South.checked_advance((0, 0)), Some((1, 0)));
+    assert_eq!(Direction::North.checked_advance((0, 3)), None);
+    assert_eq!(Direction::between(&(2, 2), &(1, 2)), Direction::North);
+    assert_eq!(Direction::between(&(2, 2), &(2, 3)), Direction::East);
+    assert_eq!(Direction::between(&(2, 2), &(3, 2)), Direction::South);
+    assert_eq!(Direction::between(&(2, 2), &(2, 1)), Direction::West);
+}
+
+#[test]
+fn compass() {
+    assert_eq!(CompassDirection::Northeast.reverse(), CompassDirection::Southwest);
+    assert_eq!(CompassDirection::West.reverse(), CompassDirection::East);
+    assert_eq!(CompassDirection::North.turn_left(), CompassDirection::West);
+    assert_eq!(CompassDirection::Northeast.turn_left(), CompassDirection::Northwest);
+    assert_eq!(CompassDirection::West.turn_right(), CompassDirection::North);
+    assert_eq!(CompassDirection::Northwest.turn_right(), CompassDirection::Northeast);
+    assert_eq!(CompassDirection::North.turn_left_45(), CompassDirection::Northwest);
+    assert_eq!(CompassDirection::Northwest.turn_right_45(), CompassDirection::North);
+    assert_eq!(CompassDirection::Southeast.turn_right_45(), CompassDirection::South);
+
+    //The turns agree with Direction's and undo each other all the way round
+    for direction in Direction::ALL {
+        assert_eq!(CompassDirection::from(direction).turn_left(), CompassDirection::from(direction.turn_left()));
+        assert_eq!(CompassDirection::from(direction).turn_right(), CompassDirection::from(direction.turn_right()));
+    }
+    for direction in CompassDirection::ALL {
+        assert_eq!(direction.turn_left_45().turn_right_45(), direction);
+        assert_eq!(direction.turn_right_45().turn_right_45(), direction.turn_right());
+        assert_eq!(direction.turn_left().turn_left(), direction.reverse());
+    }
+    assert!(CompassDirection::Northeast.points_towards(Direction::North));
+    assert!(CompassDirection::Northeast.points_towards(Direction::East));
+    assert!(!CompassDirection::Northeast.points_towards(Direction::South));
+    assert!(!CompassDirection::North.points_towards(Direction::East));
+    assert_eq!(CompassDirection::from(Direction::South), CompassDirection::South);
+}
+
+#[test]
+fn signed_positions() {
+    assert_eq!(to_signed_position((3, 4)), (3, 4));
+    assert_eq!(to_unsigned_position((3, 4)), Some((3, 4)));
+    assert_eq!(to_unsigned_position((-1, 4)), None);
+}
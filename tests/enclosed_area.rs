@@ -0,0 +1,103 @@
+//Cross-checks the day 10 enclosed area strategies against each other
+#![allow(clippy::needless_return)]
+mod common;
+use common::read_fixture;
+use sandbox::day10::*;
+use sandbox::day10_part2::solve_with_strategy;
+use sandbox::grid::Position;
+
+fn count_with_every_strategy(input: &str) -> Vec<i64> {
+    return AreaStrategy::ALL.iter().map(|x| solve_with_strategy(input, *x).unwrap().get_value()).collect();
+}
+
+//Draws a loop as pipes with S on its first tile
+fn render_loop(path: &[Position], height: usize, width: usize) -> String {
+    let mut rows = vec![vec!['.'; width]; height];
+    for (index, tile) in path.iter().enumerate() {
+        let neighbors = [path[(index + path.len() - 1) % path.len()], path[(index + 1) % path.len()]];
+        let connects = |row: isize, col: isize| neighbors.iter().any(|x| x.0 as isize == tile.0 as isize + row && x.1 as isize == tile.1 as isize + col);
+        rows[tile.0][tile.1] = match (connects(-1, 0), connects(0, 1), connects(1, 0), connects(0, -1)) {
+            (true, _, true, _) => '|',
+            (_, true, _, true) => '-',
+            (true, true, _, _) => 'L',
+            (true, _, _, true) => 'J',
+            (_, _, true, true) => '7',
+            _ => 'F'
+        };
+    }
+    rows[path[0].0][path[0].1] = 'S';
+
+    return rows.iter().map(|x| x.iter().collect::<String>()).collect::<Vec<String>>().join("\n");
+}
+
+//Rectangle whose bottom edge has teeth reaching up to row 2, two columns apart. The gaps between the teeth
+//alternate between pockets of the inside open to the top and pockets of the outside open to the bottom.
+fn comb_loop(height: usize, width: usize) -> Vec<Position> {
+    let mut corners = vec![(0, 0), (0, width - 1), (height - 1, width - 1)];
+    let mut col_index = width - 2;
+    while col_index >= 4 {
+        corners.extend([(height - 1, col_index), (2, col_index), (2, col_index - 2), (height - 1, col_index - 2)]);
+        col_index -= 4;
+    }
+    corners.push((height - 1, 0));
+
+    //Fill in the straight runs between corners, stopping short of the next corner
+    let mut path = Vec::<Position>::new();
+    for (start, end) in corners.iter().zip(corners.iter().cycle().skip(1)) {
+        let mut tile = *start;
+        while tile != *end {
+            path.push(tile);
+            tile = (step_towards(tile.0, end.0), step_towards(tile.1, end.1));
+        }
+    }
+    return path;
+}
+
+fn step_towards(from: usize, to: usize) -> usize {
+    return if from < to { from + 1 } else if from > to { from - 1 } else { from };
+}
+
+#[test]
+fn strategies_agree_on_examples() {
+    for (fixture, expected) in [("day10_part2_simple.txt", 4), ("day10_part2_squeeze.txt", 4), ("day10_part2_larger.txt", 8), ("day10_part2_junk.txt", 10)] {
+        assert_eq!(count_with_every_strategy(&read_fixture(fixture)), [expected; 3], "{}", fixture);
+    }
+}
+
+#[test]
+fn strategies_agree_on_a_comb() {
+    //Row 1 above the teeth, one inside pocket between teeth, and the three columns left of the last tooth
+    let path = comb_loop(8, 12);
+    assert_eq!(count_enclosed_by_shoelace(&path), 10 + 5 + 3 * 5);
+    assert_eq!(count_enclosed_by_scanline(&path, 8, 12), 10 + 5 + 3 * 5);
+    assert_eq!(count_with_every_strategy(&render_loop(&path, 8, 12)), [30; 3]);
+
+    let path = comb_loop(30, 40);
+    let expected = count_enclosed_by_shoelace(&path) as i64;
+    assert_eq!(count_with_every_strategy(&render_loop(&path, 30, 40)), [expected; 3]);
+}
+
+#[test]
+fn small_loops() {
+    let ring = [(0, 0), (0, 1), (0, 2), (1, 2), (2, 2), (2, 1), (2, 0), (1, 0)];
+    assert_eq!(count_enclosed_by_shoelace(&ring), 1);
+    assert_eq!(count_enclosed_by_scanline(&ring, 3, 3), 1);
+
+    //The same ring walked the other way, shifted away from the edge of a bigger grid
+    let reversed = ring.iter().rev().map(|x| (x.0 + 2, x.1 + 3)).collect::<Vec<Position>>();
+    assert_eq!(count_enclosed_by_shoelace(&reversed), 1);
+    assert_eq!(count_enclosed_by_scanline(&reversed, 6, 7), 1);
+
+    let square = [(0, 0), (0, 1), (1, 1), (1, 0)];
+    assert_eq!(count_enclosed_by_shoelace(&square), 0);
+    assert_eq!(count_enclosed_by_scanline(&square, 2, 2), 0);
+}
+
+#[test]
+fn strategy_names() {
+    for strategy in AreaStrategy::ALL {
+        assert_eq!(strategy.to_string().parse::<AreaStrategy>(), Ok(strategy));
+    }
+    assert!("flood".parse::<AreaStrategy>().is_err());
+    assert_eq!(AreaStrategy::default(), AreaStrategy::Shoelace);
+}
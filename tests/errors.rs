@@ -0,0 +1,109 @@
+//Checks that malformed inputs are reported with their position rather than panicking
+#![allow(clippy::needless_return)]
+mod common;
+use sandbox::error::SolverError;
+use sandbox::registry::find_solver;
+
+fn run_solver(day: u32, part: u32, input: &str) -> SolverError {
+    return find_solver(day, part).unwrap().run(input).unwrap_err();
+}
+
+#[test]
+fn invalid_grid_symbol() {
+    let error = run_solver(10, 1, ".....\n.S-7.\n.|X|.\n.L-J.\n.....\n");
+    assert_eq!(error, SolverError::Parse {
+        day: Some(10),
+        line: 3,
+        column: 3,
+        text: String::from("X"),
+        reason: String::from("Invalid symbol")
+    });
+    assert_eq!(error.to_string(), "Day 10: Invalid symbol at line 3, column 3: 'X'");
+}
+
+#[test]
+fn invalid_workflow_variant() {
+    let error = run_solver(19, 1, "in{q<2006:A,R}\n\n{x=1,m=2,a=3,s=4}\n");
+    assert_eq!(error, SolverError::Parse {
+        day: Some(19),
+        line: 1,
+        column: 4,
+        text: String::from("q<2006"),
+        reason: String::from("Invalid variant code")
+    });
+}
+
+#[test]
+fn invalid_number() {
+    let error = run_solver(9, 1, "0 3 6 9\n1 3 x 10\n");
+    assert_eq!(error, SolverError::Parse {
+        day: Some(9),
+        line: 2,
+        column: 5,
+        text: String::from("x"),
+        reason: String::from("Invalid number")
+    });
+}
+
+#[test]
+fn missing_start() {
+    let error = run_solver(10, 2, "...\n.F7\n.LJ\n");
+    assert_eq!(error, SolverError::InvalidInput {
+        day: Some(10),
+        reason: String::from("Could not find the starting position")
+    });
+}
+
+//Some puzzles have a sensible answer for empty input, so this only checks that nothing panics
+#[test]
+fn empty_input() {
+    for solver in sandbox::registry::SOLVERS.iter() {
+        let _ = solver.run("");
+    }
+}
+
+#[test]
+fn malformed_dig_plans() {
+    let error = run_solver(18, 1, "R 3 (#70c710)\nL 3 (#0dc571)\n");
+    assert_eq!(error, SolverError::Parse {
+        day: Some(18),
+        line: 2,
+        column: 1,
+        text: String::from("L 3 (#0dc571)"),
+        reason: String::from("The step doubles back on the one before")
+    });
+
+    //A figure eight whose fourth step runs through the first
+    let figure_eight = "R 2 (#000000)\nD 1 (#000000)\nL 1 (#000000)\nU 2 (#000000)\nL 1 (#000000)\nD 1 (#000000)\n";
+    assert!(matches!(run_solver(18, 1, figure_eight), SolverError::Parse { line: 4, .. }));
+
+    //The last step doubles back over the first, so the step before it runs into the first
+    let error = run_solver(18, 2, "R 1 (#000021)\nR 1 (#000010)\nR 1 (#000013)\nR 1 (#000012)\nR 1 (#000013)\n");
+    assert_eq!(error, SolverError::parse_line(3, "R 1 (#000012)", "The trench runs into the one dug on line 1").with_day(18));
+    assert_eq!(run_solver(18, 2, "R 1 (#000030)\nR 1 (#000011)\n"), SolverError::InvalidInput {
+        day: Some(18),
+        reason: String::from("The dig plan does not return to its starting point")
+    });
+}
+
+#[test]
+fn missing_broadcaster() {
+    assert_eq!(run_solver(20, 2, "%a -> b\n&b -> rx\n"), SolverError::InvalidInput {
+        day: Some(20),
+        reason: String::from("There is no broadcaster module")
+    });
+}
+
+#[test]
+fn no_three_wire_cut() {
+    let no_cut = SolverError::InvalidInput {
+        day: Some(25),
+        reason: String::from("No three wire cut splits the graph in two")
+    };
+    let example = common::read_fixture("day25.txt");
+    let lines = example.lines().collect::<Vec<&str>>();
+    //One component wired to three others, where every cut leaves a single wire
+    assert_eq!(run_solver(25, 1, lines[0]), no_cut);
+    //Half the example's lines leave two groups that were never connected
+    assert_eq!(run_solver(25, 1, &lines[..lines.len() / 2].join("\n")), no_cut);
+}
@@ -0,0 +1,272 @@
+//Runs every solver against the published example inputs in tests/fixtures
+#![allow(clippy::needless_return)]
+mod common;
+use common::read_fixture;
+use sandbox::registry::find_solver;
+
+fn solve_fixture(day: u32, part: u32, fixture: &str) -> i64 {
+    let solver = find_solver(day, part).unwrap();
+    return solver.run(&read_fixture(fixture)).unwrap().get_value();
+}
+
+#[test]
+fn day1_part1() {
+    assert_eq!(solve_fixture(1, 1, "day1_part1.txt"), 142);
+}
+
+#[test]
+fn day1_part2() {
+    assert_eq!(solve_fixture(1, 2, "day1_part2.txt"), 281);
+}
+
+#[test]
+fn day2_part1() {
+    assert_eq!(solve_fixture(2, 1, "day2.txt"), 8);
+}
+
+#[test]
+fn day2_part2() {
+    assert_eq!(solve_fixture(2, 2, "day2.txt"), 2286);
+}
+
+#[test]
+fn day3_part1() {
+    assert_eq!(solve_fixture(3, 1, "day3.txt"), 4361);
+}
+
+#[test]
+fn day3_part2() {
+    assert_eq!(solve_fixture(3, 2, "day3.txt"), 467835);
+}
+
+#[test]
+fn day4_part1() {
+    assert_eq!(solve_fixture(4, 1, "day4.txt"), 13);
+}
+
+#[test]
+fn day4_part2() {
+    assert_eq!(solve_fixture(4, 2, "day4.txt"), 30);
+}
+
+#[test]
+fn day5_part1() {
+    assert_eq!(solve_fixture(5, 1, "day5.txt"), 35);
+}
+
+#[test]
+fn day5_part2() {
+    assert_eq!(solve_fixture(5, 2, "day5.txt"), 46);
+}
+
+#[test]
+fn day6_part1() {
+    assert_eq!(solve_fixture(6, 1, "day6.txt"), 288);
+}
+
+#[test]
+fn day6_part2() {
+    assert_eq!(solve_fixture(6, 2, "day6.txt"), 71503);
+}
+
+#[test]
+fn day7_part1() {
+    assert_eq!(solve_fixture(7, 1, "day7.txt"), 6440);
+}
+
+#[test]
+fn day7_part2() {
+    assert_eq!(solve_fixture(7, 2, "day7.txt"), 5905);
+}
+
+#[test]
+fn day8_part1() {
+    assert_eq!(solve_fixture(8, 1, "day8_part1.txt"), 2);
+    assert_eq!(solve_fixture(8, 1, "day8_part1_repeat.txt"), 6);
+}
+
+#[test]
+fn day8_part2() {
+    assert_eq!(solve_fixture(8, 2, "day8_part2.txt"), 6);
+}
+
+#[test]
+fn day9_part1() {
+    assert_eq!(solve_fixture(9, 1, "day9.txt"), 114);
+}
+
+#[test]
+fn day9_part2() {
+    assert_eq!(solve_fixture(9, 2, "day9.txt"), 2);
+}
+
+#[test]
+fn day10_part1() {
+    assert_eq!(solve_fixture(10, 1, "day10_part1_simple.txt"), 4);
+    assert_eq!(solve_fixture(10, 1, "day10_part1_complex.txt"), 8);
+}
+
+#[test]
+fn day10_part2() {
+    assert_eq!(solve_fixture(10, 2, "day10_part2_simple.txt"), 4);
+    assert_eq!(solve_fixture(10, 2, "day10_part2_squeeze.txt"), 4);
+    assert_eq!(solve_fixture(10, 2, "day10_part2_larger.txt"), 8);
+    assert_eq!(solve_fixture(10, 2, "day10_part2_junk.txt"), 10);
+}
+
+#[test]
+fn day11_part1() {
+    assert_eq!(solve_fixture(11, 1, "day11.txt"), 374);
+}
+
+#[test]
+fn day11_part2() {
+    assert_eq!(solve_fixture(11, 2, "day11.txt"), 82000210);
+}
+
+#[test]
+fn day12_part1() {
+    assert_eq!(solve_fixture(12, 1, "day12.txt"), 21);
+}
+
+#[test]
+fn day12_part2() {
+    assert_eq!(solve_fixture(12, 2, "day12.txt"), 525152);
+}
+
+#[test]
+fn day13_part1() {
+    assert_eq!(solve_fixture(13, 1, "day13.txt"), 405);
+}
+
+#[test]
+fn day13_part2() {
+    assert_eq!(solve_fixture(13, 2, "day13.txt"), 400);
+}
+
+#[test]
+fn day14_part1() {
+    assert_eq!(solve_fixture(14, 1, "day14.txt"), 136);
+}
+
+#[test]
+fn day14_part2() {
+    assert_eq!(solve_fixture(14, 2, "day14.txt"), 64);
+}
+
+#[test]
+fn day15_part1() {
+    assert_eq!(solve_fixture(15, 1, "day15.txt"), 1320);
+}
+
+#[test]
+fn day15_part2() {
+    assert_eq!(solve_fixture(15, 2, "day15.txt"), 145);
+}
+
+#[test]
+fn day16_part1() {
+    assert_eq!(solve_fixture(16, 1, "day16.txt"), 46);
+}
+
+#[test]
+fn day16_part2() {
+    assert_eq!(solve_fixture(16, 2, "day16.txt"), 51);
+}
+
+#[test]
+fn day17_part1() {
+    assert_eq!(solve_fixture(17, 1, "day17.txt"), 102);
+}
+
+#[test]
+fn day17_part2() {
+    assert_eq!(solve_fixture(17, 2, "day17.txt"), 94);
+    assert_eq!(solve_fixture(17, 2, "day17_part2_unlucky.txt"), 71);
+}
+
+#[test]
+fn day18_part1() {
+    assert_eq!(solve_fixture(18, 1, "day18.txt"), 62);
+}
+
+#[test]
+fn day18_part2() {
+    assert_eq!(solve_fixture(18, 2, "day18.txt"), 952408144115);
+}
+
+#[test]
+fn day19_part1() {
+    assert_eq!(solve_fixture(19, 1, "day19.txt"), 19114);
+}
+
+#[test]
+fn day19_part2() {
+    assert_eq!(solve_fixture(19, 2, "day19.txt"), 167409079868000);
+}
+
+#[test]
+fn day20_part1() {
+    assert_eq!(solve_fixture(20, 1, "day20_part1.txt"), 32000000);
+    assert_eq!(solve_fixture(20, 1, "day20_part1_interesting.txt"), 11687500);
+}
+
+//There is no published example for part 2, so this is a hand built circuit. The two inputs to the
+//conjunction feeding rx emit a high pulse every 2 and 4 presses respectively.
+#[test]
+fn day20_part2() {
+    assert_eq!(solve_fixture(20, 2, "day20_part2.txt"), 4);
+}
+
+//The published example only gives 6 steps, so 64 steps was checked with a separate BFS
+#[test]
+fn day21_part1() {
+    assert_eq!(solve_fixture(21, 1, "day21.txt"), 42);
+}
+
+//The example map doesn't have the clear start row and column the extrapolation relies on, so check
+//the infinite map walk against the published step counts instead
+#[test]
+fn day21_part2() {
+    let input = read_fixture("day21.txt");
+    assert_eq!(sandbox::day21_part2::count_reachable_plots(&input, 6).unwrap(), 16);
+    assert_eq!(sandbox::day21_part2::count_reachable_plots(&input, 10).unwrap(), 50);
+    assert_eq!(sandbox::day21_part2::count_reachable_plots(&input, 50).unwrap(), 1594);
+}
+
+#[test]
+fn day22_part1() {
+    assert_eq!(solve_fixture(22, 1, "day22.txt"), 5);
+}
+
+#[test]
+fn day22_part2() {
+    assert_eq!(solve_fixture(22, 2, "day22.txt"), 7);
+}
+
+#[test]
+fn day23_part1() {
+    assert_eq!(solve_fixture(23, 1, "day23.txt"), 94);
+}
+
+#[test]
+fn day23_part2() {
+    assert_eq!(solve_fixture(23, 2, "day23.txt"), 154);
+}
+
+//The example uses a test area of 7 to 27 rather than the puzzle's
+#[test]
+fn day24_part1() {
+    let input = read_fixture("day24.txt");
+    assert_eq!(sandbox::day24_part1::count_intersections_in_bounds(&input, 7.0, 27.0).unwrap(), 2);
+}
+
+#[test]
+fn day24_part2() {
+    assert_eq!(solve_fixture(24, 2, "day24.txt"), 47);
+}
+
+#[test]
+fn day25() {
+    assert_eq!(solve_fixture(25, 1, "day25.txt"), 54);
+}
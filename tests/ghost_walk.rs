@@ -0,0 +1,122 @@
+//Checks the day 8 part 2 cycle detection on walks that don't line up the way the puzzle input does
+#![allow(clippy::needless_return)]
+use sandbox::day8::DesertNetwork;
+use sandbox::day8_part2::*;
+use sandbox::day8_part2::scalar_solver_iterator::*;
+use sandbox::error::SolverError;
+
+//Every instruction is L, so each node's left step is the only one taken
+fn left_only_input(steps: &[(&str, &str)]) -> String {
+    let nodes = steps.iter().map(|(from, to)| format!("{} = ({}, {})", from, to, to)).collect::<Vec<String>>();
+    return format!("L\n\n{}\n", nodes.join("\n"));
+}
+
+//Ghosts that step from their start onto a loop of the given lengths, hitting Z on the loop nodes where
+//is_end(ghost, node) holds. Node 0 is reached after one step.
+fn ring_input(lengths: &[usize], is_end: impl Fn(usize, usize) -> bool) -> String {
+    let mut steps = Vec::<(String, String)>::new();
+    for (ghost, length) in lengths.iter().enumerate() {
+        let name = |x: usize| format!("{}_{}{}", ghost, x, if is_end(ghost, x) { "Z" } else { "B" });
+        steps.push((format!("{}A", ghost), name(0)));
+        steps.extend((0..*length).map(|x| (name(x), name((x + 1) % length))));
+    }
+
+    return left_only_input(&steps.iter().map(|(from, to)| (from.as_str(), to.as_str())).collect::<Vec<(&str, &str)>>());
+}
+
+//Hits Z at steps 2, 5, 8, ... after a one step prefix
+const OFFSET_THREE: [(&str, &str); 4] = [("11A", "11B"), ("11B", "11Z"), ("11Z", "11C"), ("11C", "11B")];
+//Hits Z at every odd step
+const ODD: [(&str, &str); 3] = [("22A", "22Z"), ("22Z", "22B"), ("22B", "22Z")];
+//Hits Z at step 1 and never again
+const ONCE: [(&str, &str); 3] = [("33A", "33Z"), ("33Z", "33B"), ("33B", "33B")];
+//Hits Z at every even step
+const EVEN: [(&str, &str); 3] = [("44A", "44B"), ("44B", "44Z"), ("44Z", "44B")];
+
+#[test]
+fn detects_prefix_and_cycle() {
+    let network = DesertNetwork::parse(&left_only_input(&OFFSET_THREE)).unwrap();
+    let cycle = ScalarSolverIterator::new(network.get_id("11A").unwrap(), &network).detect_cycle();
+    assert_eq!((cycle.get_prefix_length(), cycle.get_cycle_length()), (1, 3));
+    assert!(cycle.get_prefix_hits().is_empty());
+    assert_eq!(cycle.get_cycle_hits(), [2]);
+    assert!(cycle.is_hit(2) && cycle.is_hit(8) && !cycle.is_hit(7));
+
+    //Detecting from partway along only reports the steps from there on
+    let mut iterator = ScalarSolverIterator::new(network.get_id("11A").unwrap(), &network);
+    assert_eq!(iterator.next(), Some(ScalarSolverResult { index: 0, iteration: 2 }));
+    let cycle = iterator.detect_cycle();
+    assert_eq!((cycle.get_prefix_length(), cycle.get_cycle_length(), cycle.get_cycle_hits()), (2, 3, [2].as_slice()));
+
+    let network = DesertNetwork::parse(&left_only_input(&ONCE)).unwrap();
+    let cycle = ScalarSolverIterator::new(network.get_id("33A").unwrap(), &network).detect_cycle();
+    assert_eq!((cycle.get_prefix_length(), cycle.get_cycle_length()), (2, 1));
+    assert_eq!(cycle.get_prefix_hits(), [1]);
+    assert!(cycle.get_cycle_hits().is_empty());
+}
+
+#[test]
+fn cycles_keyed_on_instruction_index() {
+    //Each node is visited at both instruction indices, so the walk only repeats after four steps
+    let network = DesertNetwork::parse("LR\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, AAA)\n").unwrap();
+    let cycle = ScalarSolverIterator::new(network.get_id("AAA").unwrap(), &network).detect_cycle();
+    assert_eq!((cycle.get_prefix_length(), cycle.get_cycle_length()), (0, 4));
+    assert_eq!(cycle.get_cycle_hits(), [2, 3]);
+    assert!(cycle.is_hit(7) && !cycle.is_hit(8));
+}
+
+#[test]
+fn misaligned_ghosts() {
+    //Steps 2 mod 3 and odd first meet at step 5, which the old LCM shortcut got wrong
+    assert_eq!(solve(&left_only_input(&[OFFSET_THREE.as_slice(), ODD.as_slice()].concat())).unwrap().get_value(), 5);
+    assert_eq!(solve(&left_only_input(&[ODD.as_slice(), ONCE.as_slice()].concat())).unwrap().get_value(), 1);
+    assert_eq!(solve(&left_only_input(&[OFFSET_THREE.as_slice(), EVEN.as_slice()].concat())).unwrap().get_value(), 2);
+}
+
+#[test]
+fn ghosts_that_never_meet() {
+    let never = SolverError::invalid_input("The ghosts never all reach nodes ending in Z at the same time");
+    assert_eq!(solve(&left_only_input(&[ODD.as_slice(), EVEN.as_slice()].concat())).unwrap_err(), never);
+    assert_eq!(solve(&left_only_input(&[ONCE.as_slice(), EVEN.as_slice()].concat())).unwrap_err(), never);
+}
+
+#[test]
+fn many_hits_per_ghost() {
+    //Every combination of hits would be about 50^5 congruences
+    let lengths = [101, 103, 107, 109, 113];
+    assert_eq!(solve(&ring_input(&lengths, |_, x| x % 2 == 0)).unwrap().get_value(), 1);
+
+    for divisor in [2, 3, 5] {
+        let input = ring_input(&lengths, |_, x| x % divisor == divisor - 1);
+        let network = DesertNetwork::parse(&input).unwrap();
+        let expected = (1i64..)
+            .find(|x| network.get_start_nodes().iter().all(|start| network.is_end(network.walk(*start, *x as u64))))
+            .unwrap();
+        assert_eq!(solve(&input).unwrap().get_value(), expected, "Z on every {} nodes", divisor);
+    }
+}
+
+#[test]
+fn cycles_beyond_u64() {
+    //The lcm of the cycle lengths doesn't fit in a u64, but every ghost is on Z after one step
+    let lengths = [1009, 1013, 1019, 1021, 1031, 1033, 1039];
+    assert_eq!(solve(&ring_input(&lengths, |_, x| x == 0)).unwrap().get_value(), 1);
+
+    //The first six line up every lcm of their lengths after step 1, and the fifth time the last one joins them
+    let fifth = |ghost, x| if ghost == 6 { x == 382 } else { x == 0 };
+    assert_eq!(solve(&ring_input(&lengths, fifth)).unwrap().get_value(), 1 + 5 * 1_132_555_580_906_002_709);
+
+    //Here they first line up after about 1.2e21 steps
+    let error = SolverError::invalid_input("The ghosts don't all reach nodes ending in Z within the u64 limit");
+    assert_eq!(solve(&ring_input(&lengths, |ghost, x| x == ghost)).unwrap_err(), error);
+}
+
+#[test]
+fn candidate_scan_is_bounded() {
+    //The first three ghosts combine into thousands of candidates, and the last two are on Z at steps of opposite
+    //parity, so no candidate ever matches. Checking all of them up to the period would take about 3e10 checks.
+    let lengths = [101, 103, 107, 109, 226, 254];
+    let is_end = |ghost, x| if ghost < 4 { x % 5 == 0 } else { x % 2 == ghost - 4 };
+    let error = SolverError::invalid_input("The ghosts don't all reach nodes ending in Z within the candidate steps checked");
+    assert_eq!(solve(&ring_input(&lengths, is_end)).unwrap_err(), error);
+}
@@ -0,0 +1,94 @@
+//Checks the shared grid used by the map based days
+#![allow(clippy::needless_return)]
+use sandbox::error::SolverError;
+use sandbox::grid::*;
+
+fn sample_grid() -> Grid<char> {
+    return Grid::parse("abc\ndef\n", Some).unwrap();
+}
+
+#[test]
+fn parse() {
+    let grid = sample_grid();
+    assert_eq!(grid.get_width(), 3);
+    assert_eq!(grid.get_height(), 2);
+    assert_eq!(grid[(1, 2)], 'f');
+    assert_eq!(grid.get((2, 0)), None);
+    assert_eq!(grid.position(|&x| x == 'e'), Some((1, 1)));
+}
+
+#[test]
+fn parse_errors() {
+    let invalid_symbol = Grid::parse("..\n.x\n", |x| (x == '.').then_some(x)).unwrap_err();
+    assert_eq!(invalid_symbol, SolverError::Parse {
+        day: None,
+        line: 2,
+        column: 2,
+        text: String::from("x"),
+        reason: String::from("Invalid symbol")
+    });
+
+    let ragged = Grid::parse("...\n..\n", Some).unwrap_err();
+    assert_eq!(ragged.to_string(), "Row length differs from the first row at line 2, column 1: '..'");
+
+    assert_eq!(Grid::parse("", Some).unwrap_err(), SolverError::invalid_input("Input is empty"));
+
+    //Lines from the middle of an input keep their own line numbers
+    let offset = Grid::parse_lines([(4, ".."), (5, "x.")], |x| (x == '.').then_some(x)).unwrap_err();
+    assert!(matches!(offset, SolverError::Parse { line: 6, column: 1, .. }));
+}
+
+#[test]
+fn neighbors() {
+    let grid = Grid::new(3, 3, 0);
+    assert_eq!(grid.neighbors4((1, 1)).collect::<Vec<Position>>(), vec![(0, 1), (1, 2), (2, 1), (1, 0)]);
+    assert_eq!(grid.neighbors4((0, 0)).collect::<Vec<Position>>(), vec![(0, 1), (1, 0)]);
+    assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+    assert_eq!(grid.neighbors8((2, 2)).collect::<Vec<Position>>(), vec![(1, 1), (1, 2), (2, 1)]);
+}
+
+#[test]
+fn step_and_wrap() {
+    let grid = sample_grid();
+    assert_eq!(grid.step((0, 0), (1, 2)), Some((1, 2)));
+    assert_eq!(grid.step((0, 0), (-1, 0)), None);
+    assert_eq!(grid.step((1, 2), (0, 1)), None);
+    assert_eq!(grid.wrap(-1, 3), (1, 0));
+    assert_eq!(grid.wrap(5, -4), (1, 2));
+}
+
+#[test]
+fn rows_and_columns() {
+    let grid = sample_grid();
+    assert_eq!(grid.row(1), ['d', 'e', 'f']);
+    assert_eq!(grid.rows().count(), 2);
+    assert_eq!(grid.column(2).collect::<String>(), "cf");
+    assert_eq!(grid.columns().map(|x| x.collect::<String>()).collect::<Vec<String>>(), vec!["ad", "be", "cf"]);
+}
+
+#[test]
+fn transform() {
+    let grid = sample_grid();
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+    assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+    assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+    assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    assert_eq!(grid.map(|x| x.to_ascii_uppercase()).to_string(), "ABC\nDEF\n");
+}
+
+#[test]
+fn insert() {
+    let mut grid = sample_grid();
+    grid.insert_row(1, '.');
+    grid.insert_column(3, '#');
+    grid.insert_column(0, '#');
+    assert_eq!(grid.to_string(), "#abc#\n#...#\n#def#\n");
+    assert_eq!((grid.get_width(), grid.get_height()), (5, 3));
+}
+
+#[test]
+fn render() {
+    let grid = Grid::from_rows(vec![vec![true, false], vec![false, true]]).unwrap();
+    assert_eq!(grid.render(|&x| if x { '#' } else { '.' }), "#.\n.#\n");
+    assert_eq!(Grid::from_rows(vec![vec![1, 2], vec![3]]), None);
+}
@@ -0,0 +1,33 @@
+//Checks the machine-readable output formats
+use std::time::Duration;
+use sandbox::answer::Answer;
+use sandbox::output::*;
+use sandbox::registry::find_solver;
+
+#[test]
+fn json_format() {
+    let solver = find_solver(17, 2).unwrap();
+    let line = format_answer(solver, Answer(1215), Duration::from_micros(1500), OutputFormat::Json);
+    let value = serde_json::from_str::<serde_json::Value>(&line).unwrap();
+    assert_eq!(value, serde_json::json!({"day": 17, "part": 2, "answer": 1215, "elapsed_ms": 1.5}));
+}
+
+#[test]
+fn plain_format() {
+    let solver = find_solver(17, 2).unwrap();
+    assert_eq!(format_answer(solver, Answer(1215), Duration::ZERO, OutputFormat::Plain), "1215");
+}
+
+#[test]
+fn text_format() {
+    let solver = find_solver(17, 2).unwrap();
+    assert_eq!(format_answer(solver, Answer(1215), Duration::ZERO, OutputFormat::Text), solver.describe(&Answer(1215)));
+}
+
+#[test]
+fn parse_format() {
+    assert_eq!("json".parse::<OutputFormat>(), Ok(OutputFormat::Json));
+    assert_eq!("plain".parse::<OutputFormat>(), Ok(OutputFormat::Plain));
+    assert_eq!("text".parse::<OutputFormat>(), Ok(OutputFormat::Text));
+    assert!("xml".parse::<OutputFormat>().is_err());
+}
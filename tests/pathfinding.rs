@@ -0,0 +1,56 @@
+//Checks the shared shortest path searches
+#![allow(clippy::needless_return)]
+use sandbox::grid::*;
+use sandbox::pathfinding::*;
+
+//Directed graph where the direct edge a -> d is more expensive than going around
+fn sample_successors(node: &char) -> Vec<(char, u32)> {
+    return match node {
+        'a' => vec![('b', 1), ('c', 4), ('d', 10)],
+        'b' => vec![('c', 1), ('e', 7)],
+        'c' => vec![('d', 2)],
+        'd' => vec![('e', 1)],
+        _ => vec![]
+    };
+}
+
+#[test]
+fn dijkstra_finds_cheapest_path() {
+    let result = dijkstra('a', sample_successors, |&x| x == 'd').unwrap();
+    assert_eq!(result.get_cost(), 4);
+    assert_eq!(result.get_path(), ['a', 'b', 'c', 'd']);
+    assert_eq!(*result.get_goal(), 'd');
+}
+
+#[test]
+fn first_goal_reached_wins() {
+    let result = dijkstra('a', sample_successors, |&x| x == 'd' || x == 'e').unwrap();
+    assert_eq!(result.get_cost(), 4);
+    assert_eq!(result.into_path(), vec!['a', 'b', 'c', 'd']);
+
+    let start_is_goal = dijkstra('a', sample_successors, |_| true).unwrap();
+    assert_eq!((start_is_goal.get_cost(), start_is_goal.get_path()), (0, &['a'][..]));
+}
+
+#[test]
+fn unreachable_goal() {
+    assert_eq!(dijkstra('a', sample_successors, |&x| x == 'z'), None);
+    assert_eq!(dijkstra('e', sample_successors, |&x| x == 'a'), None);
+}
+
+#[test]
+fn astar_matches_dijkstra() {
+    let grid = Grid::parse("1163\n1381\n2136\n3691\n", |x| x.to_digit(10)).unwrap();
+    let goal = (3, 3);
+    let successors = |position: &Position| grid
+        .neighbors4(*position)
+        .map(|x| (x, grid[x]))
+        .collect::<Vec<(Position, u32)>>();
+
+    let without_heuristic = dijkstra((0, 0), successors, |&x| x == goal).unwrap();
+    let with_heuristic = astar((0, 0), successors, |x| (goal.0 - x.0 + goal.1 - x.1) as u32, |&x| x == goal).unwrap();
+    assert_eq!(without_heuristic.get_cost(), 14);
+    assert_eq!(with_heuristic.get_cost(), 14);
+    assert_eq!(with_heuristic.get_path().first(), Some(&(0, 0)));
+    assert_eq!(*with_heuristic.get_goal(), goal);
+}
@@ -0,0 +1,104 @@
+//Checks the range set and piecewise-linear range map used by day 5
+#![allow(clippy::needless_return)]
+use sandbox::range_set::*;
+
+fn set(ranges: &[(u64, u64)]) -> RangeSet {
+    return ranges.iter().map(|&(start, end)| start..end).collect();
+}
+
+//Seed to soil and soil to fertilizer maps from the day 5 example
+fn example_maps() -> (RangeMap, RangeMap) {
+    let mut seed_soil = RangeMap::new();
+    seed_soil.insert(98..100, 50);
+    seed_soil.insert(50..98, 52);
+
+    let mut soil_fertilizer = RangeMap::new();
+    soil_fertilizer.insert(15..52, 0);
+    soil_fertilizer.insert(52..54, 37);
+    soil_fertilizer.insert(0..15, 39);
+
+    return (seed_soil, soil_fertilizer);
+}
+
+#[test]
+fn insert_merges_ranges() {
+    let ranges = set(&[(10, 20), (30, 40), (20, 25), (50, 60), (35, 55)]);
+    assert_eq!(ranges.get_ranges(), [10..25, 30..60]);
+    assert_eq!(ranges.get_size(), 45);
+    assert_eq!(ranges.get_min(), Some(10));
+    assert!(ranges.contains(24));
+    assert!(!ranges.contains(25));
+    assert!(ranges.contains(30));
+    assert!(!ranges.contains(60));
+
+    let empty = set(&[(5, 5)]);
+    assert!(empty.is_empty());
+    assert_eq!(empty.get_min(), None);
+}
+
+#[test]
+fn set_operations() {
+    let a = set(&[(0, 10), (20, 30), (40, 50)]);
+    let b = set(&[(5, 25), (45, 60)]);
+    assert_eq!(a.union(&b).get_ranges(), [0..30, 40..60]);
+    assert_eq!(a.intersection(&b).get_ranges(), [5..10, 20..25, 45..50]);
+    assert_eq!(a.difference(&b).get_ranges(), [0..5, 25..30, 40..45]);
+    assert_eq!(b.difference(&a).get_ranges(), [10..20, 50..60]);
+    assert_eq!(a.difference(&set(&[(2, 3), (4, 6), (8, 42)])).get_ranges(), [0..2, 3..4, 6..8, 42..50]);
+    assert!(a.difference(&a).is_empty());
+}
+
+#[test]
+fn map_values() {
+    let (seed_soil, _) = example_maps();
+    assert_eq!([79, 14, 55, 13, 98, 99, 100].map(|x| seed_soil.map_value(x)), [81, 14, 57, 13, 50, 51, 100]);
+
+    let mut overlapping = seed_soil.clone();
+    assert!(!overlapping.insert(95..110, 0));
+    assert_eq!(overlapping, seed_soil);
+}
+
+#[test]
+fn map_ranges() {
+    let (seed_soil, _) = example_maps();
+    let mapped = seed_soil.map_ranges(&set(&[(45, 100)]));
+    assert_eq!(mapped, set(&[(45, 100)]));
+
+    let mapped = seed_soil.map_ranges(&set(&[(79, 93), (55, 68)]));
+    assert_eq!(mapped.get_ranges(), [57..70, 81..95]);
+}
+
+#[test]
+fn compose_matches_mapping_in_sequence() {
+    let (seed_soil, soil_fertilizer) = example_maps();
+    let seed_fertilizer = seed_soil.compose(&soil_fertilizer);
+    for seed in 0..120 {
+        assert_eq!(seed_fertilizer.map_value(seed), soil_fertilizer.map_value(seed_soil.map_value(seed)), "seed {}", seed);
+    }
+
+    let seeds = set(&[(79, 93), (55, 68), (0, 20)]);
+    assert_eq!(seed_fertilizer.map_ranges(&seeds), soil_fertilizer.map_ranges(&seed_soil.map_ranges(&seeds)));
+    assert_eq!(RangeMap::new().compose(&seed_soil), seed_soil);
+}
+
+#[test]
+fn preimage_and_inverse() {
+    let (seed_soil, soil_fertilizer) = example_maps();
+    let seed_fertilizer = seed_soil.compose(&soil_fertilizer);
+    let fertilizer_seed = seed_fertilizer.inverse().unwrap();
+    for seed in 0..120 {
+        assert_eq!(fertilizer_seed.map_value(seed_fertilizer.map_value(seed)), seed, "seed {}", seed);
+    }
+
+    let fertilizers = RangeSet::from(50..60);
+    let seeds = seed_fertilizer.preimage(&fertilizers);
+    assert_eq!(seeds, fertilizer_seed.map_ranges(&fertilizers));
+    assert_eq!(seed_fertilizer.map_ranges(&seeds), fertilizers);
+
+    //Both 0..5 and 10..15 land on 10..15, so there is no inverse but the preimage holds both
+    let mut merging = RangeMap::new();
+    merging.insert(0..5, 10);
+    assert_eq!(merging.inverse(), None);
+    assert_eq!(merging.preimage(&RangeSet::from(12..20)).get_ranges(), [2..5, 12..20]);
+    assert!(merging.preimage(&RangeSet::from(0..5)).is_empty());
+}
@@ -0,0 +1,102 @@
+//Checks the day 9 polynomial fit and its exact extrapolation
+#![allow(clippy::needless_return)]
+mod common;
+use common::read_fixture;
+use num::{BigInt, BigRational};
+use sandbox::day9::*;
+use sandbox::day9::sequence::*;
+use sandbox::error::SolverError;
+
+fn whole(value: i64) -> BigRational {
+    return BigRational::from_integer(BigInt::from(value));
+}
+
+#[test]
+fn example_sequences() {
+    let linear = Sequence::fit(&[0, 3, 6, 9, 12, 15]).unwrap();
+    assert_eq!(linear.get_degree(), 1);
+    assert_eq!(linear.value_at(6), whole(18));
+    assert_eq!(linear.value_at(-1), whole(-3));
+
+    let quadratic = Sequence::fit(&[1, 3, 6, 10, 15, 21]).unwrap();
+    assert_eq!(quadratic.get_degree(), 2);
+    assert_eq!(quadratic.get_newton_coefficients(), [whole(1), whole(2), whole(1)]);
+    assert_eq!(quadratic.value_at(6), whole(28));
+    assert_eq!(quadratic.value_at(-1), whole(0));
+
+    let cubic = Sequence::fit(&[10, 13, 16, 21, 30, 45]).unwrap();
+    assert_eq!(cubic.get_degree(), 3);
+    assert_eq!(cubic.value_at(6), whole(68));
+    assert_eq!(cubic.value_at(-1), whole(5));
+}
+
+#[test]
+fn reproduces_the_input() {
+    //n^3 - 5n^2 + 3
+    let values = [3, -1, -9, -15, -13, 3, 39];
+    let sequence = Sequence::fit(&values).unwrap();
+    assert_eq!(sequence.get_degree(), 3);
+    for (index, value) in values.iter().enumerate() {
+        assert_eq!(sequence.value_at(index as i64), whole(*value));
+    }
+}
+
+#[test]
+fn constant_sequences() {
+    assert_eq!(Sequence::fit(&[7, 7, 7]).unwrap().get_degree(), 0);
+    assert_eq!(Sequence::fit(&[7, 7, 7]).unwrap().value_at(-1000), whole(7));
+    assert_eq!(Sequence::fit(&[0]).unwrap().value_at(5), whole(0));
+}
+
+#[test]
+fn far_away_indices() {
+    //n^3, well past where an i64 could hold the values
+    let cube = Sequence::fit(&[0, 1, 8, 27, 64]).unwrap();
+    let index = BigInt::from(10).pow(20);
+    assert_eq!(cube.value_at(index.clone()), BigRational::from_integer(index.pow(3)));
+    assert_eq!(cube.value_at(-index.clone()), BigRational::from_integer(-index.pow(3)));
+
+    //Values near the limits of an i64 extrapolate past them
+    let sequence = Sequence::fit(&[i64::MIN + 1, 0, i64::MAX]).unwrap();
+    assert_eq!(sequence.get_degree(), 1);
+    assert_eq!(sequence.value_at(3), BigRational::from_integer(BigInt::from(i64::MAX) * 2));
+    assert_eq!(sequence.value_at(-1), BigRational::from_integer(BigInt::from(i64::MAX) * -2));
+    assert_eq!(to_answer(&sequence.value_at(3)), Err(SolverError::invalid_input("The result 18446744073709551614 does not fit in an i64")));
+    assert_eq!(to_answer(&sequence.value_at(2)).unwrap().get_value(), i64::MAX);
+}
+
+#[test]
+fn sequences_without_a_zero_row() {
+    let error = Err(String::from("The differences never reach a row of zeros"));
+    assert_eq!(Sequence::fit(&[1, 2, 4, 8]), error);
+    assert_eq!(Sequence::fit(&[5]), error);
+    assert_eq!(Sequence::fit(&[]), error);
+}
+
+#[test]
+fn diff_tree() {
+    let tree = generate_diff_tree(&[0, 3, 6, 9].map(BigInt::from).to_vec());
+    assert_eq!(tree, [vec![0, 3, 6, 9], vec![3, 3, 3], vec![0, 0]].map(|x| x.into_iter().map(BigInt::from).collect::<Vec<BigInt>>()));
+}
+
+#[test]
+fn extrapolate_both_ways() {
+    let quadratic = Sequence::fit(&[1, 3, 6, 10, 15, 21]).unwrap();
+    assert_eq!(quadratic.extrapolate_forward(3), [whole(28), whole(36), whole(45)]);
+    assert_eq!(quadratic.extrapolate_backward(3), [whole(0), whole(0), whole(1)]);
+    assert!(quadratic.extrapolate_forward(0).is_empty());
+}
+
+#[test]
+fn solve_in_either_direction() {
+    let example = read_fixture("day9.txt");
+    assert_eq!(solve_with_extrapolation(&example, Extrapolation::Forward, 1).unwrap().get_value(), 114);
+    assert_eq!(solve_with_extrapolation(&example, Extrapolation::Backward, 1).unwrap().get_value(), 2);
+    //One step further out than the puzzle asks for, and the end values themselves
+    assert_eq!(solve_with_extrapolation(&example, Extrapolation::Forward, 2).unwrap().get_value(), 21 + 36 + 101);
+    assert_eq!(solve_with_extrapolation(&example, Extrapolation::Backward, 2).unwrap().get_value(), -6 - 4);
+    assert_eq!(solve_with_extrapolation(&example, Extrapolation::Forward, 0).unwrap().get_value(), 15 + 21 + 45);
+
+    assert_eq!("backward".parse::<Extrapolation>(), Ok(Extrapolation::Backward));
+    assert!("sideways".parse::<Extrapolation>().is_err());
+}
@@ -0,0 +1,90 @@
+//Checks the answers file parsing and the verdicts reported by verify
+mod common;
+use std::env;
+use std::fs;
+use std::process::Command;
+use common::{crate_path, read_fixture};
+use sandbox::registry::{find_solver, SOLVERS};
+use sandbox::verify::*;
+
+const ANSWERS: &str = "\
+[day9]
+part1 = 114
+part2 = 3
+";
+
+#[test]
+fn parse_answers() {
+    let answer_key = AnswerKey::parse(ANSWERS).unwrap();
+    assert_eq!(answer_key.get(9, 1), Some(114));
+    assert_eq!(answer_key.get(9, 2), Some(3));
+    assert_eq!(answer_key.get(10, 1), None);
+
+    assert!(AnswerKey::parse("[9]\npart1 = 1\n").is_err());
+    assert!(AnswerKey::parse("[day9]\nfirst = 1\n").is_err());
+    assert!(AnswerKey::parse("[day9]\npart1 = \"one\"\n").is_err());
+}
+
+#[test]
+fn verdicts() {
+    let answer_key = AnswerKey::parse(ANSWERS).unwrap();
+    let input = read_fixture("day9.txt");
+
+    assert_eq!(verify_answer(find_solver(9, 1).unwrap(), &input, &answer_key), Verdict::Pass);
+    assert_eq!(verify_answer(find_solver(9, 2).unwrap(), &input, &answer_key), Verdict::Fail { expected: 3, actual: 2 });
+    assert_eq!(verify_answer(find_solver(6, 1).unwrap(), &read_fixture("day6.txt"), &answer_key), Verdict::Missing { actual: 288 });
+    assert!(matches!(verify_answer(find_solver(9, 1).unwrap(), "1 2 x", &answer_key), Verdict::Error(_)));
+}
+
+//Days whose parts have separate examples use the one that both parts can solve
+const SHARED_FIXTURES: [(u32, &str); 4] = [
+    (1, "day1_part1.txt"),
+    (8, "day8_part1.txt"),
+    (10, "day10_part1_simple.txt"),
+    (20, "day20_part2.txt")
+];
+
+#[test]
+fn whole_series_finishes() {
+    //Lay out the examples the way AOC_INPUT_DIR expects, with each answer recorded from the library
+    let input_dir = env::temp_dir().join(format!("aoc_verify_series_{}", std::process::id()));
+    fs::create_dir_all(&input_dir).unwrap();
+    let mut answers = String::new();
+    for day in 1..=25 {
+        let fixture = SHARED_FIXTURES.iter().find(|x| x.0 == day).map_or(format!("day{}.txt", day), |x| x.1.to_string());
+        let input = read_fixture(&fixture);
+        fs::write(input_dir.join(format!("day{}.txt", day)), &input).unwrap();
+
+        answers += &format!("[day{}]\n", day);
+        for solver in SOLVERS.iter().filter(|x| x.day == day) {
+            answers += &format!("part{} = {}\n", solver.part, solver.run(&input).unwrap().get_value());
+        }
+    }
+    fs::write(input_dir.join(ANSWERS_FILE_NAME), answers).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .arg("verify")
+        .env("AOC_INPUT_DIR", &input_dir)
+        .output()
+        .unwrap();
+    fs::remove_dir_all(&input_dir).unwrap();
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(output.status.success(), "{}", stdout);
+    assert_eq!(stdout.lines().filter(|x| x.ends_with("PASS")).count(), SOLVERS.len());
+    assert!(stdout.ends_with(&format!("{} passed, 0 failed, 0 missing, 0 errors\n", SOLVERS.len())));
+}
+
+#[test]
+fn day18_part2_full_input() {
+    //The full dig plan used to be drawn block by block, which ran out of memory before verify could finish
+    let answer_key = AnswerKey::load(&crate_path(ANSWERS_FILE_NAME)).unwrap();
+    let solver = find_solver(18, 2).unwrap();
+    let input = fs::read_to_string(crate_path(solver.default_input)).unwrap();
+    assert_eq!(verify_answer(solver, &input, &answer_key), Verdict::Pass);
+}
+
+#[test]
+fn answers_file_is_valid() {
+    AnswerKey::load(&crate_path(ANSWERS_FILE_NAME)).unwrap();
+}